@@ -0,0 +1,193 @@
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{ToTokens, format_ident, quote};
+use syn::parse::Parser;
+use syn::spanned::Spanned;
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    Expr, FnArg, GenericArgument, GenericParam, Ident, Item, ItemFn, Pat, Path, PathArguments,
+    ReturnType, Type, parse_quote, parse_quote_spanned,
+};
+
+pub(crate) fn expand(args: TokenStream, mut item: ItemFn) -> syn::Result<TokenStream> {
+    let mut associated = false;
+    let mut krate: Path = parse_quote!(::result_option);
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("associated") {
+            associated = true;
+            Ok(())
+        } else if meta.path.is_ident("crate") {
+            krate = meta.value()?.parse()?;
+            Ok(())
+        } else {
+            Err(meta.error("expected `associated` or `crate = path`"))
+        }
+    });
+    Parser::parse2(parser, args)?;
+    let associated = associated || mentions_self(item.sig.to_token_stream());
+    let (ok_ty, err_ty) = result_option_args(&item.sig.output)?;
+
+    let mut companion = companion(&item, associated, &ok_ty, &err_ty)?;
+    RewriteTry { krate }.visit_block_mut(&mut item.block);
+    companion.attrs.extend(
+        item.attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+            .cloned(),
+    );
+    Ok(quote! {
+        #item
+        #companion
+    })
+}
+
+/// Extracts `T` and `E` from a `ResultOption<T, E>` return type.
+fn result_option_args(output: &ReturnType) -> syn::Result<(Type, Type)> {
+    let error = || {
+        syn::Error::new(
+            output.span(),
+            "`#[result_option]` requires a return type of `ResultOption<T, E>`",
+        )
+    };
+    let ReturnType::Type(_, ty) = output else {
+        return Err(error());
+    };
+    let Type::Path(path) = &**ty else {
+        return Err(error());
+    };
+    let segment = path.path.segments.last().ok_or_else(error)?;
+    if segment.ident != "ResultOption" {
+        return Err(error());
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return Err(error());
+    };
+    let mut types = args.args.iter().filter_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty.clone()),
+        _ => None,
+    });
+    match (types.next(), types.next(), types.next()) {
+        (Some(ok), Some(err), None) => Ok((ok, err)),
+        _ => Err(error()),
+    }
+}
+
+/// Builds `<name>_nested`, which calls the function and converts its result into
+/// `Result<Option<T>, E>`.
+fn companion(item: &ItemFn, associated: bool, ok_ty: &Type, err_ty: &Type) -> syn::Result<ItemFn> {
+    let name = &item.sig.ident;
+    let mut sig = item.sig.clone();
+    sig.ident = format_ident!("{}_nested", name);
+    sig.constness = None;
+    sig.output = parse_quote! {
+        -> ::core::result::Result<::core::option::Option<#ok_ty>, #err_ty>
+    };
+
+    let mut receiver = None;
+    let mut args = Vec::new();
+    let mut has_impl_trait = false;
+    for (index, input) in sig.inputs.iter_mut().enumerate() {
+        match input {
+            FnArg::Receiver(_) => receiver = Some(quote!(self)),
+            FnArg::Typed(typed) => {
+                has_impl_trait |= matches!(*typed.ty, Type::ImplTrait(_));
+                let ident = match &*typed.pat {
+                    Pat::Ident(pat) if pat.by_ref.is_none() && pat.subpat.is_none() => {
+                        pat.ident.clone()
+                    }
+                    _ => Ident::new(&format!("arg{index}"), Span::call_site()),
+                };
+                *typed.pat = parse_quote!(#ident);
+                args.push(ident);
+            }
+        }
+    }
+
+    let turbofish = (!has_impl_trait)
+        .then(|| {
+            let params: Vec<_> = item
+                .sig
+                .generics
+                .params
+                .iter()
+                .filter_map(|param| match param {
+                    GenericParam::Type(ty) => Some(ty.ident.clone()),
+                    GenericParam::Const(c) => Some(c.ident.clone()),
+                    GenericParam::Lifetime(_) => None,
+                })
+                .collect();
+            (!params.is_empty()).then(|| quote!(::<#(#params),*>))
+        })
+        .flatten();
+    // Methods and associated functions are only reachable through `Self` in doc links.
+    let target = if receiver.is_some() || associated {
+        format!("Self::{name}")
+    } else {
+        name.to_string()
+    };
+    let call = match receiver {
+        Some(receiver) => quote!(#receiver.#name #turbofish(#(#args),*)),
+        None if associated => quote!(Self::#name #turbofish(#(#args),*)),
+        None => quote!(#name #turbofish(#(#args),*)),
+    };
+    let call = match item.sig.asyncness {
+        Some(_) => quote!(#call.await),
+        None => call,
+    };
+    let call = match item.sig.unsafety {
+        Some(_) => quote!(unsafe { #call }),
+        None => call,
+    };
+
+    let vis = &item.vis;
+    let doc = format!(
+        "Calls [`{name}`]({target}) and converts its `ResultOption` into the nested `Result<Option<T>, E>`."
+    );
+    Ok(parse_quote! {
+        #[doc = #doc]
+        #[inline]
+        #vis #sig {
+            ::core::convert::From::from(#call)
+        }
+    })
+}
+
+/// Returns `true` if `tokens` mention `Self`, so the function is an associated function
+/// whose companion must call it through `Self::`.
+fn mentions_self(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == "Self",
+        TokenTree::Group(group) => mentions_self(group.stream()),
+        _ => false,
+    })
+}
+
+/// Rewrites `expr?` into an early return of `ResultOption::None` or `ResultOption::Err`,
+/// leaving closures, async blocks and nested items alone, as they have their own `?` scope.
+struct RewriteTry {
+    /// The path to the `result_option` crate.
+    krate: Path,
+}
+
+impl VisitMut for RewriteTry {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Closure(_) | Expr::Async(_) | Expr::TryBlock(_) => {}
+            Expr::Try(try_expr) => {
+                self.visit_expr_mut(&mut try_expr.expr);
+                let inner = &try_expr.expr;
+                let krate = &self.krate;
+                let ro = quote!(#krate::ResultOption);
+                *expr = parse_quote_spanned! {try_expr.question_token.span=>
+                    match #krate::__private::Question::question(#inner) {
+                        #ro::Ok(value) => value,
+                        #ro::None => return #ro::None,
+                        #ro::Err(error) => return #ro::Err(error),
+                    }
+                };
+            }
+            _ => visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+
+    fn visit_item_mut(&mut self, _: &mut Item) {}
+}
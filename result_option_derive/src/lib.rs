@@ -0,0 +1,415 @@
+//! Derive macros for the [`result_option`](https://docs.rs/result_option) crate.
+//!
+//! Use them through the `derive` feature of `result_option`, which re-exports them.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    Data, DeriveInput, Fields, Ident, ItemFn, Member, Path, Type, Variant, parse_macro_input,
+    parse_quote,
+};
+
+mod attribute;
+
+/// Derives conversions to and from `ResultOption<T, E>`, the `ThreeWay` trait, and the
+/// common query and unwrap methods for an enum shaped like `ResultOption`.
+///
+/// See the `ThreeWay` derive re-exported by `result_option` for details.
+#[proc_macro_derive(ThreeWay, attributes(three_way))]
+pub fn derive_three_way(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Lets a function returning `ResultOption<T, E>` use `?` on `Option`, `Result` and
+/// `ResultOption`, and generates a `<name>_nested` companion returning
+/// `Result<Option<T>, E>`.
+///
+/// See the `result_option` attribute re-exported by `result_option` for details.
+#[proc_macro_attribute]
+pub fn result_option(args: TokenStream, input: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(input as ItemFn);
+    attribute::expand(args.into(), item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The role of a variant, mirroring the variants of `ResultOption`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Role {
+    Ok,
+    None,
+    Err,
+}
+
+/// A variant with a single field, holding the `Ok` value or the error.
+struct ValueVariant<'a> {
+    ident: &'a Ident,
+    member: Member,
+    ty: &'a Type,
+}
+
+impl ValueVariant<'_> {
+    /// Returns a pattern or constructor for this variant of `ty` with its field bound to
+    /// `binding`.
+    fn with(&self, ty: &Ident, binding: &Ident) -> TokenStream2 {
+        let ident = self.ident;
+        match &self.member {
+            Member::Named(field) => quote!(#ty::#ident { #field: #binding }),
+            Member::Unnamed(_) => quote!(#ty::#ident(#binding)),
+        }
+    }
+}
+
+/// Options set on the enum itself with `#[three_way(...)]`.
+struct ContainerOptions {
+    skip_unwrap: bool,
+    /// The path to the `result_option` crate, `::result_option` unless set with
+    /// `crate = path`.
+    krate: Path,
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "`ThreeWay` can only be derived for enums",
+        ));
+    };
+    let options = container_options(input)?;
+
+    let mut ok = None;
+    let mut none = None;
+    let mut err = None;
+    let mut unassigned = Vec::new();
+    for variant in &data.variants {
+        match variant_role(variant)? {
+            Some(Role::Ok) => assign(&mut ok, variant, "ok")?,
+            Some(Role::None) => assign(&mut none, variant, "none")?,
+            Some(Role::Err) => assign(&mut err, variant, "err")?,
+            Option::None => unassigned.push(variant),
+        }
+    }
+    // Without attributes, the unit variant is `None` and the variants with a field are
+    // `Ok` and `Err`, in declaration order.
+    for variant in unassigned {
+        let slot = if matches!(variant.fields, Fields::Unit) {
+            &mut none
+        } else if ok.is_none() {
+            &mut ok
+        } else {
+            &mut err
+        };
+        assign(slot, variant, "inferred")?;
+    }
+
+    let missing = |role: &str| {
+        syn::Error::new(
+            Span::call_site(),
+            format!(
+                "`ThreeWay` requires a variant for `{role}`, mark it with `#[three_way({role})]`"
+            ),
+        )
+    };
+    let ok = value_variant(ok.ok_or_else(|| missing("ok"))?)?;
+    let none = none.ok_or_else(|| missing("none"))?;
+    if !matches!(none.fields, Fields::Unit) {
+        return Err(syn::Error::new_spanned(
+            none,
+            "the `none` variant must not have fields",
+        ));
+    }
+    let err = value_variant(err.ok_or_else(|| missing("err"))?)?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let ok_ty = ok.ty;
+    let err_ty = err.ty;
+    let none_ident = &none.ident;
+    let value = Ident::new("value", Span::call_site());
+    let this = Ident::new("Self", Span::call_site());
+    let ok_pat = ok.with(&this, &value);
+    let err_pat = err.with(&this, &value);
+    let named_ok_pat = ok.with(name, &value);
+    let named_err_pat = err.with(name, &value);
+    let krate = &options.krate;
+    let ro = quote!(#krate::ResultOption);
+    // Intra-doc links need the path without the spaces `quote` puts between tokens.
+    let krate_link = quote!(#krate).to_string().replace(' ', "");
+    let see =
+        |method: &str| format!("[`ResultOption::{method}`]({krate_link}::ResultOption::{method})");
+    let unwrap_doc = format!("Returns the `Ok` value, see {}.", see("unwrap"));
+    let expect_doc = format!("Returns the `Ok` value, see {}.", see("expect"));
+    let unwrap_err_doc = format!("Returns the error, see {}.", see("unwrap_err"));
+    let expect_err_doc = format!("Returns the error, see {}.", see("expect_err"));
+    let unwrap_option_doc = format!(
+        "Returns the `Ok` value as an `Option`, see {}.",
+        see("unwrap_option")
+    );
+    let kind_doc = format!("Returns the kind of the value, see {}.", see("kind"));
+    let ok_doc = format!("Returns the `Ok` value, if any, see {}.", see("ok"));
+    let err_doc = format!("Returns the error, if any, see {}.", see("err"));
+    let unwrap_or_doc = format!(
+        "Returns the `Ok` value or `default`, see {}.",
+        see("unwrap_or")
+    );
+    let unwrap_or_else_doc = format!(
+        "Returns the `Ok` value or computes one, see {}.",
+        see("unwrap_or_else")
+    );
+
+    let unwrap_methods = (!options.skip_unwrap).then(|| {
+        quote! {
+            #[doc = #unwrap_doc]
+            #[inline]
+            #[track_caller]
+            pub fn unwrap(self) -> #ok_ty
+            where
+                #err_ty: ::core::fmt::Debug,
+            {
+                #ro::from(self).unwrap()
+            }
+
+            #[doc = #expect_doc]
+            #[inline]
+            #[track_caller]
+            pub fn expect(self, msg: &str) -> #ok_ty
+            where
+                #err_ty: ::core::fmt::Debug,
+            {
+                #ro::from(self).expect(msg)
+            }
+
+            #[doc = #unwrap_err_doc]
+            #[inline]
+            #[track_caller]
+            pub fn unwrap_err(self) -> #err_ty
+            where
+                #ok_ty: ::core::fmt::Debug,
+            {
+                #ro::from(self).unwrap_err()
+            }
+
+            #[doc = #expect_err_doc]
+            #[inline]
+            #[track_caller]
+            pub fn expect_err(self, msg: &str) -> #err_ty
+            where
+                #ok_ty: ::core::fmt::Debug,
+            {
+                #ro::from(self).expect_err(msg)
+            }
+
+            #[doc = #unwrap_option_doc]
+            #[inline]
+            #[track_caller]
+            pub fn unwrap_option(self) -> ::core::option::Option<#ok_ty>
+            where
+                #err_ty: ::core::fmt::Debug,
+            {
+                #ro::from(self).unwrap_option()
+            }
+        }
+    });
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for #ro<#ok_ty, #err_ty> #where_clause {
+            #[inline]
+            fn from(value: #name #ty_generics) -> Self {
+                match value {
+                    #name::#none_ident => #ro::None,
+                    #named_ok_pat => #ro::Ok(value),
+                    #named_err_pat => #ro::Err(value),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<#ro<#ok_ty, #err_ty>> for #name #ty_generics #where_clause {
+            #[inline]
+            fn from(value: #ro<#ok_ty, #err_ty>) -> Self {
+                match value {
+                    #ro::Ok(value) => #ok_pat,
+                    #ro::None => Self::#none_ident,
+                    #ro::Err(value) => #err_pat,
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #krate::ThreeWay for #name #ty_generics #where_clause {
+            type Ok = #ok_ty;
+            type Err = #err_ty;
+
+            #[inline]
+            fn into_ro(self) -> #ro<#ok_ty, #err_ty> {
+                #ro::from(self)
+            }
+
+            #[inline]
+            fn from_ro(ro: #ro<#ok_ty, #err_ty>) -> Self {
+                Self::from(ro)
+            }
+
+            #[inline]
+            fn kind(&self) -> #krate::ResultOptionKind {
+                Self::kind(self)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Borrows the value as a `ResultOption` of references.
+            #[inline]
+            pub const fn as_result_option(&self) -> #ro<&#ok_ty, &#err_ty> {
+                match self {
+                    Self::#none_ident => #ro::None,
+                    #ok_pat => #ro::Ok(value),
+                    #err_pat => #ro::Err(value),
+                }
+            }
+
+            /// Converts the value into a `ResultOption`.
+            #[inline]
+            pub fn into_result_option(self) -> #ro<#ok_ty, #err_ty> {
+                #ro::from(self)
+            }
+
+            #[doc = #kind_doc]
+            #[inline]
+            pub const fn kind(&self) -> #krate::ResultOptionKind {
+                self.as_result_option().kind()
+            }
+
+            /// Returns `true` for the `Ok` variant.
+            #[inline]
+            pub const fn is_ok(&self) -> bool {
+                self.as_result_option().is_ok()
+            }
+
+            /// Returns `true` for the `None` variant.
+            #[inline]
+            pub const fn is_none(&self) -> bool {
+                self.as_result_option().is_none()
+            }
+
+            /// Returns `true` for the `Err` variant.
+            #[inline]
+            pub const fn is_err(&self) -> bool {
+                self.as_result_option().is_err()
+            }
+
+            #[doc = #ok_doc]
+            #[inline]
+            pub fn ok(self) -> ::core::option::Option<#ok_ty> {
+                #ro::from(self).ok()
+            }
+
+            #[doc = #err_doc]
+            #[inline]
+            pub fn err(self) -> ::core::option::Option<#err_ty> {
+                #ro::from(self).err()
+            }
+
+            #[doc = #unwrap_or_doc]
+            #[inline]
+            pub fn unwrap_or(self, default: #ok_ty) -> #ok_ty {
+                #ro::from(self).unwrap_or(default)
+            }
+
+            #[doc = #unwrap_or_else_doc]
+            #[inline]
+            pub fn unwrap_or_else<F: ::core::ops::FnOnce() -> #ok_ty>(self, f: F) -> #ok_ty {
+                #ro::from(self).unwrap_or_else(f)
+            }
+
+            #unwrap_methods
+        }
+    })
+}
+
+fn container_options(input: &DeriveInput) -> syn::Result<ContainerOptions> {
+    let mut options = ContainerOptions {
+        skip_unwrap: false,
+        krate: parse_quote!(::result_option),
+    };
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("three_way"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip_unwrap") {
+                options.skip_unwrap = true;
+                Ok(())
+            } else if meta.path.is_ident("crate") {
+                options.krate = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("expected `skip_unwrap` or `crate = path`"))
+            }
+        })?;
+    }
+    Ok(options)
+}
+
+fn variant_role(variant: &Variant) -> syn::Result<Option<Role>> {
+    let mut role = None;
+    for attr in variant
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("three_way"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let parsed = if meta.path.is_ident("ok") {
+                Role::Ok
+            } else if meta.path.is_ident("none") {
+                Role::None
+            } else if meta.path.is_ident("err") {
+                Role::Err
+            } else {
+                return Err(meta.error("expected `ok`, `none` or `err`"));
+            };
+            if role.replace(parsed).is_some() {
+                return Err(meta.error("a variant can only have one role"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(role)
+}
+
+fn assign<'a>(slot: &mut Option<&'a Variant>, variant: &'a Variant, role: &str) -> syn::Result<()> {
+    if slot.replace(variant).is_some() {
+        let message = if role == "inferred" {
+            "`ThreeWay` requires exactly one unit variant and two variants with one field each"
+                .to_string()
+        } else {
+            format!("more than one variant is marked `#[three_way({role})]`")
+        };
+        return Err(syn::Error::new_spanned(variant, message));
+    }
+    Ok(())
+}
+
+fn value_variant(variant: &Variant) -> syn::Result<ValueVariant<'_>> {
+    let mut fields = variant.fields.iter();
+    match (fields.next(), fields.next()) {
+        (Some(field), Option::None) => Ok(ValueVariant {
+            ident: &variant.ident,
+            member: field
+                .ident
+                .clone()
+                .map_or_else(|| Member::from(0), Member::Named),
+            ty: &field.ty,
+        }),
+        _ => Err(syn::Error::new_spanned(
+            variant,
+            "the `ok` and `err` variants must have exactly one field",
+        )),
+    }
+}
@@ -0,0 +1,130 @@
+//! Validation-style combinators that accumulate every error instead of stopping at the first.
+//!
+//! As with the other combinators, `Err` dominates `None`: the result is `None` only
+//! when there are no errors at all.
+
+use crate::ResultOption;
+
+impl<C, D> ResultOption<C, D> {
+    /// Collects every `Ok` value and every `Err` value of an iterator.
+    ///
+    /// Returns `Err` with all the errors if there is any, otherwise `None` if any item is
+    /// `None`, otherwise `Ok` with all the values. Unlike `collect`, this always consumes
+    /// the whole iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let fields = [
+    ///     ResultOption::Ok("alice"),
+    ///     ResultOption::Err("missing email"),
+    ///     ResultOption::None,
+    ///     ResultOption::Err("invalid age"),
+    /// ];
+    /// let checked: ResultOption<Vec<_>, Vec<_>> = ResultOption::collect_all_errors(fields);
+    /// assert_eq!(checked, ResultOption::Err(vec!["missing email", "invalid age"]));
+    ///
+    /// let fields: [ResultOption<&str, &str>; 2] = [ResultOption::Ok("alice"), ResultOption::Ok("bob")];
+    /// let checked: ResultOption<Vec<_>, Vec<_>> = ResultOption::collect_all_errors(fields);
+    /// assert_eq!(checked, ResultOption::Ok(vec!["alice", "bob"]));
+    /// ```
+    pub fn collect_all_errors<T, E, I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = ResultOption<T, E>>,
+        C: Default + Extend<T>,
+        D: Default + Extend<E>,
+    {
+        let mut oks = C::default();
+        let mut errs = D::default();
+        let mut none_seen = false;
+        let mut err_seen = false;
+        for item in iter {
+            match item {
+                ResultOption::Ok(t) => oks.extend(Some(t)),
+                ResultOption::None => none_seen = true,
+                ResultOption::Err(e) => {
+                    err_seen = true;
+                    errs.extend(Some(e));
+                }
+            }
+        }
+        if err_seen {
+            Self::Err(errs)
+        } else if none_seen {
+            Self::None
+        } else {
+            Self::Ok(oks)
+        }
+    }
+}
+
+impl<T, E> ResultOption<T, E> {
+    /// Pairs the `Ok` values of `self` and `other`, collecting the errors of both sides.
+    ///
+    /// Returns `Err` with the errors of both sides if there is any, otherwise `None` if
+    /// either side is `None`, otherwise `Ok` with both values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let name: ResultOption<&str, &str> = ResultOption::Err("missing name");
+    /// let age: ResultOption<u32, &str> = ResultOption::Err("invalid age");
+    /// assert_eq!(name.zip_accumulate(age), ResultOption::Err(vec!["missing name", "invalid age"]));
+    ///
+    /// let name: ResultOption<&str, &str> = ResultOption::Ok("alice");
+    /// let age: ResultOption<u32, &str> = ResultOption::Ok(30);
+    /// assert_eq!(name.zip_accumulate(age), ResultOption::Ok(("alice", 30)));
+    /// ```
+    pub fn zip_accumulate<U>(self, other: ResultOption<U, E>) -> ResultOption<(T, U), Vec<E>> {
+        match (self, other) {
+            (Self::Ok(t), ResultOption::Ok(u)) => ResultOption::Ok((t, u)),
+            (Self::Err(e), ResultOption::Err(f)) => ResultOption::Err(vec![e, f]),
+            (Self::Err(e), _) | (_, ResultOption::Err(e)) => ResultOption::Err(vec![e]),
+            (Self::None, _) | (_, ResultOption::None) => ResultOption::None,
+        }
+    }
+
+    /// Pairs the `Ok` values of `self` and `other`, merging the errors of both sides with
+    /// `combine`.
+    ///
+    /// `combine` is only called when both sides are `Err`. This works with any error type
+    /// that can be merged, such as a `Vec` or a user-defined error report, and allows
+    /// chaining several checks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// fn merge(mut a: Vec<String>, b: Vec<String>) -> Vec<String> {
+    ///     a.extend(b);
+    ///     a
+    /// }
+    ///
+    /// let name: ResultOption<&str, Vec<String>> = ResultOption::Err(vec!["missing name".into()]);
+    /// let age: ResultOption<u32, Vec<String>> = ResultOption::Err(vec!["invalid age".into()]);
+    /// let email: ResultOption<&str, Vec<String>> = ResultOption::Ok("alice@example.com");
+    ///
+    /// let user = name.zip_accumulate_with(age, merge).zip_accumulate_with(email, merge);
+    /// assert_eq!(user.unwrap_err(), ["missing name", "invalid age"]);
+    /// ```
+    pub fn zip_accumulate_with<U, F>(
+        self,
+        other: ResultOption<U, E>,
+        combine: F,
+    ) -> ResultOption<(T, U), E>
+    where
+        F: FnOnce(E, E) -> E,
+    {
+        match (self, other) {
+            (Self::Ok(t), ResultOption::Ok(u)) => ResultOption::Ok((t, u)),
+            (Self::Err(e), ResultOption::Err(f)) => ResultOption::Err(combine(e, f)),
+            (Self::Err(e), _) | (_, ResultOption::Err(e)) => ResultOption::Err(e),
+            (Self::None, _) | (_, ResultOption::None) => ResultOption::None,
+        }
+    }
+}
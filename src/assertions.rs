@@ -0,0 +1,199 @@
+/// Asserts that a `ResultOption` is `Ok`, optionally checking its payload.
+///
+/// - `assert_ok!(ro)` evaluates to the `Ok` payload.
+/// - `assert_ok!(ro, expected)` also asserts that the payload equals `expected`, and
+///   evaluates to the payload.
+/// - `assert_ok!(ro, matches pattern)` asserts that the payload matches `pattern`, with an
+///   optional `if` guard.
+///
+/// `ro` is taken by value; use [`as_ref`](crate::ResultOption::as_ref) to keep it.
+///
+/// # Panics
+///
+/// Panics if `ro` is not `Ok`, printing the actual value with [`Debug`](std::fmt::Debug),
+/// or if the payload does not equal `expected` or match `pattern`.
+///
+/// # Examples
+///
+/// ```
+/// use result_option::{assert_ok, ResultOption};
+///
+/// let x: ResultOption<u32, &str> = ResultOption::Ok(42);
+///
+/// assert_eq!(assert_ok!(x), 42);
+/// assert_ok!(x, 42);
+/// assert_ok!(x, matches 40..=49);
+/// assert_ok!(x, matches n if n % 2 == 0);
+/// ```
+///
+/// ```should_panic
+/// use result_option::{assert_ok, ResultOption};
+///
+/// let x: ResultOption<u32, &str> = ResultOption::Err("timeout");
+///
+/// // panics with: assertion failed: expected `Ok(..)`, got `Err("timeout")`
+/// assert_ok!(x);
+/// ```
+#[macro_export]
+macro_rules! assert_ok {
+    ($ro:expr, matches $pat:pat $(if $guard:expr)? $(,)?) => {
+        match $ro {
+            $crate::ResultOption::Ok(ok) => match ok {
+                $pat $(if $guard)? => {}
+                ref ok => ::core::panic!(
+                    "assertion failed: `Ok` payload `{:?}` does not match `{}`",
+                    ok,
+                    ::core::stringify!($pat $(if $guard)?),
+                ),
+            },
+            ref other => ::core::panic!(
+                "assertion failed: expected `Ok(..)`, got `{:?}`",
+                other,
+            ),
+        }
+    };
+    ($ro:expr, $expected:expr $(,)?) => {
+        match $ro {
+            $crate::ResultOption::Ok(ok) => {
+                match (&ok, &$expected) {
+                    (ok, expected) => {
+                        if !(*ok == *expected) {
+                            ::core::panic!(
+                                "assertion `ok == expected` failed\n      ok: {:?}\nexpected: {:?}",
+                                ok,
+                                expected,
+                            );
+                        }
+                    }
+                }
+                ok
+            }
+            ref other => ::core::panic!(
+                "assertion failed: expected `Ok(..)`, got `{:?}`",
+                other,
+            ),
+        }
+    };
+    ($ro:expr $(,)?) => {
+        match $ro {
+            $crate::ResultOption::Ok(ok) => ok,
+            ref other => ::core::panic!(
+                "assertion failed: expected `Ok(..)`, got `{:?}`",
+                other,
+            ),
+        }
+    };
+}
+
+/// Asserts that a `ResultOption` is `None`.
+///
+/// # Panics
+///
+/// Panics if `ro` is not `None`, printing the actual value with [`Debug`](std::fmt::Debug).
+///
+/// # Examples
+///
+/// ```
+/// use result_option::{assert_none, ResultOption};
+///
+/// let x: ResultOption<u32, &str> = ResultOption::None;
+/// assert_none!(x);
+/// ```
+///
+/// ```should_panic
+/// use result_option::{assert_none, ResultOption};
+///
+/// let x: ResultOption<u32, &str> = ResultOption::Ok(42);
+///
+/// // panics with: assertion failed: expected `None`, got `Ok(42)`
+/// assert_none!(x);
+/// ```
+#[macro_export]
+macro_rules! assert_none {
+    ($ro:expr $(,)?) => {
+        match $ro {
+            $crate::ResultOption::None => {}
+            ref other => ::core::panic!("assertion failed: expected `None`, got `{:?}`", other),
+        }
+    };
+}
+
+/// Asserts that a `ResultOption` is `Err`, optionally checking its payload.
+///
+/// The forms mirror [`assert_ok!`]: `assert_err!(ro)` and `assert_err!(ro, expected)`
+/// evaluate to the error, and `assert_err!(ro, matches pattern)` checks the error against
+/// a pattern, with an optional `if` guard.
+///
+/// # Panics
+///
+/// Panics if `ro` is not `Err`, printing the actual value with [`Debug`](std::fmt::Debug),
+/// or if the error does not equal `expected` or match `pattern`.
+///
+/// # Examples
+///
+/// ```
+/// use result_option::{assert_err, ResultOption};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum LookupError {
+///     NotFound,
+///     Io(std::io::ErrorKind),
+/// }
+///
+/// let x: ResultOption<u32, LookupError> = ResultOption::Err(LookupError::NotFound);
+/// assert_err!(x, LookupError::NotFound);
+///
+/// let y: ResultOption<u32, LookupError> =
+///     ResultOption::Err(LookupError::Io(std::io::ErrorKind::TimedOut));
+/// assert_err!(y, matches LookupError::Io(_));
+/// ```
+#[macro_export]
+macro_rules! assert_err {
+    ($ro:expr, matches $pat:pat $(if $guard:expr)? $(,)?) => {
+        match $ro {
+            $crate::ResultOption::Err(err) => match err {
+                $pat $(if $guard)? => {}
+                ref err => ::core::panic!(
+                    "assertion failed: `Err` payload `{:?}` does not match `{}`",
+                    err,
+                    ::core::stringify!($pat $(if $guard)?),
+                ),
+            },
+            ref other => ::core::panic!(
+                "assertion failed: expected `Err(..)`, got `{:?}`",
+                other,
+            ),
+        }
+    };
+    ($ro:expr, $expected:expr $(,)?) => {
+        match $ro {
+            $crate::ResultOption::Err(err) => {
+                match (&err, &$expected) {
+                    (err, expected) => {
+                        if !(*err == *expected) {
+                            ::core::panic!(
+                                "assertion `err == expected` failed\n     err: {:?}\nexpected: {:?}",
+                                err,
+                                expected,
+                            );
+                        }
+                    }
+                }
+                err
+            }
+            ref other => ::core::panic!(
+                "assertion failed: expected `Err(..)`, got `{:?}`",
+                other,
+            ),
+        }
+    };
+    ($ro:expr $(,)?) => {
+        match $ro {
+            $crate::ResultOption::Err(err) => err,
+            ref other => ::core::panic!(
+                "assertion failed: expected `Err(..)`, got `{:?}`",
+                other,
+            ),
+        }
+    };
+}
@@ -0,0 +1,290 @@
+//! Attaching context messages to errors, in the spirit of `anyhow::Context`.
+
+use crate::ResultOption;
+use core::fmt::{self, Debug, Display, Formatter};
+use std::error::Error;
+
+/// An error wrapped in one or more context messages.
+///
+/// Created by the methods of [`ResultOptionContext`]. It keeps the original error as
+/// its source, if there is one, and every message attached on the way up.
+///
+/// Its `Display` implementation shows the outermost message only; the alternate
+/// form (`{:#}`) shows the whole chain, outermost first, followed by the source.
+///
+/// Like `anyhow::Error`, `ContextError` does not implement [`std::error::Error`] itself,
+/// so that attaching more context extends the existing chain instead of nesting a new
+/// `ContextError`. It converts into `Box<dyn Error + Send + Sync>` with `?`, where each
+/// message becomes a layer of [`Error::source`].
+///
+/// # Examples
+///
+/// ```
+/// use result_option::{ResultOption, ResultOptionContext};
+/// use std::num::ParseIntError;
+///
+/// let port: ResultOption<u16, ParseIntError> = "http".parse::<u16>().map(Some).into();
+/// let port = port
+///     .context("invalid port")
+///     .context("failed to load config");
+///
+/// let err = port.unwrap_err();
+/// assert_eq!(err.to_string(), "failed to load config");
+/// assert_eq!(
+///     format!("{err:#}"),
+///     "failed to load config: invalid port: invalid digit found in string"
+/// );
+/// ```
+pub struct ContextError<E> {
+    /// Context messages, innermost first
+    pub(crate) messages: Vec<String>,
+    pub(crate) source: Option<E>,
+}
+
+impl<E> ContextError<E> {
+    /// Creates a `ContextError` with a message and no source error.
+    #[inline]
+    pub fn msg<C: Display>(context: C) -> Self {
+        Self {
+            messages: vec![context.to_string()],
+            source: None,
+        }
+    }
+
+    /// Wraps a source error with a message.
+    #[inline]
+    pub fn new<C: Display>(source: E, context: C) -> Self {
+        Self {
+            messages: vec![context.to_string()],
+            source: Some(source),
+        }
+    }
+
+    /// Attaches another, outer message.
+    #[inline]
+    pub fn context<C: Display>(mut self, context: C) -> Self {
+        self.messages.push(context.to_string());
+        self
+    }
+
+    /// Returns the context messages, outermost first.
+    #[inline]
+    pub fn messages(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        self.messages.iter().rev().map(String::as_str)
+    }
+
+    /// Returns the wrapped source error, if there is one.
+    #[must_use]
+    #[inline]
+    pub fn source_error(&self) -> Option<&E> {
+        self.source.as_ref()
+    }
+
+    /// Returns the wrapped source error, if there is one, discarding the messages.
+    #[must_use]
+    #[inline]
+    pub fn into_source(self) -> Option<E> {
+        self.source
+    }
+}
+
+impl<E: Display> Display for ContextError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut messages = self.messages();
+        match (messages.next(), &self.source) {
+            (Some(outermost), _) => f.write_str(outermost)?,
+            // `context_none` passes an existing error through without a message
+            (None, Some(source)) => return write!(f, "{source}"),
+            (None, None) => return Ok(()),
+        }
+        if f.alternate() {
+            for message in messages {
+                write!(f, ": {message}")?;
+            }
+            if let Some(source) = &self.source {
+                write!(f, ": {source}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<E: Debug> Debug for ContextError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContextError")
+            .field("messages", &self.messages().collect::<Vec<_>>())
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+/// One message of a `ContextError`, exposed as a standard error whose source is the
+/// next inner message or, for the innermost one, the original error.
+struct ContextLayer {
+    message: String,
+    source: Option<Box<dyn Error + Send + Sync + 'static>>,
+}
+
+impl Display for ContextLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Debug for ContextLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.message)?;
+        if let Some(source) = &self.source {
+            write!(f, " caused by {source:?}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ContextLayer {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref().map(|e| e as &(dyn Error + 'static))
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<ContextError<E>>
+    for Box<dyn Error + Send + Sync + 'static>
+{
+    /// Converts into a boxed standard error, with one [`Error::source`] layer per message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ResultOption, ResultOptionContext};
+    /// use std::error::Error;
+    ///
+    /// fn load(raw: &str) -> Result<u16, Box<dyn Error + Send + Sync>> {
+    ///     let port: ResultOption<u16, _> = raw.parse::<u16>().map(Some).into();
+    ///     Ok(port.context("invalid port").context_none("port missing")?)
+    /// }
+    ///
+    /// let err = load("http").unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid port");
+    /// assert_eq!(err.source().unwrap().to_string(), "invalid digit found in string");
+    /// ```
+    fn from(error: ContextError<E>) -> Self {
+        let mut boxed = error.source.map(|e| Box::new(e) as Self);
+        for message in error.messages {
+            boxed = Some(Box::new(ContextLayer {
+                message,
+                source: boxed,
+            }));
+        }
+        boxed.unwrap_or_else(|| {
+            Box::new(ContextLayer {
+                message: String::new(),
+                source: None,
+            })
+        })
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<ContextError<E>> for Box<dyn Error + 'static> {
+    /// Converts into a boxed standard error, with one [`Error::source`] layer per message.
+    #[inline]
+    fn from(error: ContextError<E>) -> Self {
+        Box::<dyn Error + Send + Sync>::from(error)
+    }
+}
+
+/// Attaches context messages to the `Err` of a [`ResultOption`].
+///
+/// Implemented for `ResultOption<T, E>` where `E` is a standard error, and for
+/// `ResultOption<T, ContextError<E>>`, where new messages extend the existing chain.
+///
+/// # Examples
+///
+/// ```
+/// use result_option::{ResultOption, ResultOptionContext};
+/// use std::io;
+///
+/// fn find_user(id: u32) -> ResultOption<String, io::Error> {
+///     match id {
+///         1 => ResultOption::Ok("alice".to_string()),
+///         2 => ResultOption::Err(io::Error::other("connection reset")),
+///         _ => ResultOption::None,
+///     }
+/// }
+///
+/// let user = find_user(2).with_context(|| format!("failed to look up user {}", 2));
+/// assert_eq!(
+///     format!("{:#}", user.unwrap_err()),
+///     "failed to look up user 2: connection reset"
+/// );
+///
+/// // Absence can be promoted to an error when it is unexpected
+/// let user = find_user(3).context_none("user 3 must exist");
+/// assert_eq!(user.unwrap_err().to_string(), "user 3 must exist");
+///
+/// let user = find_user(2).context_none("user 2 must exist");
+/// assert_eq!(user.unwrap_err().to_string(), "connection reset");
+/// ```
+pub trait ResultOptionContext<T>: Sized {
+    /// The error type after attaching context.
+    type Error;
+
+    /// Wraps the `Err` value with a context message. `Ok` and `None` are left unchanged.
+    fn context<C: Display>(self, context: C) -> ResultOption<T, Self::Error>;
+
+    /// Wraps the `Err` value with a lazily computed context message. `Ok` and `None`
+    /// are left unchanged.
+    fn with_context<C: Display, F: FnOnce() -> C>(self, f: F) -> ResultOption<T, Self::Error>;
+
+    /// Turns `None` into an `Err` carrying the context message, for when absence is
+    /// unexpected. An existing `Err` is passed through without the message.
+    fn context_none<C: Display>(self, context: C) -> Result<T, Self::Error>;
+}
+
+impl<T, E: Error> ResultOptionContext<T> for ResultOption<T, E> {
+    type Error = ContextError<E>;
+
+    #[inline]
+    fn context<C: Display>(self, context: C) -> ResultOption<T, ContextError<E>> {
+        self.map_err(|e| ContextError::new(e, context))
+    }
+
+    #[inline]
+    fn with_context<C: Display, F: FnOnce() -> C>(self, f: F) -> ResultOption<T, ContextError<E>> {
+        self.map_err(|e| ContextError::new(e, f()))
+    }
+
+    #[inline]
+    fn context_none<C: Display>(self, context: C) -> Result<T, ContextError<E>> {
+        match self {
+            Self::Ok(t) => Ok(t),
+            Self::None => Err(ContextError::msg(context)),
+            Self::Err(e) => Err(ContextError {
+                messages: Vec::new(),
+                source: Some(e),
+            }),
+        }
+    }
+}
+
+impl<T, E> ResultOptionContext<T> for ResultOption<T, ContextError<E>> {
+    type Error = ContextError<E>;
+
+    #[inline]
+    fn context<C: Display>(self, context: C) -> ResultOption<T, ContextError<E>> {
+        self.map_err(|e| e.context(context))
+    }
+
+    #[inline]
+    fn with_context<C: Display, F: FnOnce() -> C>(self, f: F) -> ResultOption<T, ContextError<E>> {
+        self.map_err(|e| e.context(f()))
+    }
+
+    #[inline]
+    fn context_none<C: Display>(self, context: C) -> Result<T, ContextError<E>> {
+        match self {
+            Self::Ok(t) => Ok(t),
+            Self::None => Err(ContextError::msg(context)),
+            Self::Err(e) => Err(e),
+        }
+    }
+}
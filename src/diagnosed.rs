@@ -0,0 +1,159 @@
+use crate::ResultOption;
+
+/// A [`ResultOption`] carrying non-fatal diagnostics alongside its outcome.
+///
+/// Any of the three outcomes can come with warnings, for example a configuration value
+/// that was found, but under a deprecated key. Combinators such as [`and_then`](Diagnosed::and_then)
+/// keep the warnings collected so far and append the new ones.
+///
+/// # Examples
+///
+/// ```
+/// use result_option::{Diagnosed, ResultOption};
+///
+/// fn lookup(key: &str) -> Diagnosed<u32, String, String> {
+///     match key {
+///         "timeout" => Diagnosed::new(ResultOption::Ok(30)),
+///         "time_out" => Diagnosed::new(ResultOption::Ok(30))
+///             .with_warning(format!("`{key}` is deprecated, use `timeout`")),
+///         _ => Diagnosed::new(ResultOption::None),
+///     }
+/// }
+///
+/// let mut logged = Vec::new();
+/// let timeout = lookup("time_out").map(|secs| secs * 1000).finish(|w| logged.push(w));
+///
+/// assert_eq!(timeout, ResultOption::Ok(30_000));
+/// assert_eq!(logged, ["`time_out` is deprecated, use `timeout`"]);
+/// ```
+#[must_use = "This `Diagnosed` should be handled"]
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Diagnosed<T, E, W> {
+    /// The outcome
+    pub result: ResultOption<T, E>,
+    /// The non-fatal diagnostics collected so far, oldest first
+    pub warnings: Vec<W>,
+}
+
+impl<T, E, W> Diagnosed<T, E, W> {
+    /// Wraps an outcome without any warnings.
+    #[inline]
+    pub const fn new(result: ResultOption<T, E>) -> Self {
+        Self {
+            result,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Wraps an outcome together with warnings.
+    #[inline]
+    pub const fn with_warnings(result: ResultOption<T, E>, warnings: Vec<W>) -> Self {
+        Self { result, warnings }
+    }
+
+    /// Appends a warning, returning `self` for chaining.
+    #[inline]
+    pub fn with_warning(mut self, warning: W) -> Self {
+        self.warnings.push(warning);
+        self
+    }
+
+    /// Appends a warning.
+    #[inline]
+    pub fn push_warning(&mut self, warning: W) {
+        self.warnings.push(warning);
+    }
+
+    /// Returns `true` if there are any warnings.
+    #[must_use]
+    #[inline]
+    pub fn has_warnings(&self) -> bool {
+        !self.warnings.is_empty()
+    }
+
+    /// Maps the `Ok` value using the provided function, keeping the warnings.
+    #[inline]
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Diagnosed<U, E, W> {
+        Diagnosed {
+            result: self.result.map(f),
+            warnings: self.warnings,
+        }
+    }
+
+    /// Maps the `Err` value using the provided function, keeping the warnings.
+    #[inline]
+    pub fn map_err<F, O: FnOnce(E) -> F>(self, f: O) -> Diagnosed<T, F, W> {
+        Diagnosed {
+            result: self.result.map_err(f),
+            warnings: self.warnings,
+        }
+    }
+
+    /// Calls `f` with the `Ok` value, appending its warnings to the ones collected so far.
+    ///
+    /// `None` and `Err` are left unchanged, and `f` is not called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{Diagnosed, ResultOption};
+    ///
+    /// let found: Diagnosed<&str, &str, &str> =
+    ///     Diagnosed::new(ResultOption::Ok("42")).with_warning("deprecated key");
+    ///
+    /// let parsed = found.and_then(|s| {
+    ///     Diagnosed::new(s.parse::<u32>().map_err(|_| "not a number").map(Some).into())
+    ///         .with_warning("value should be quoted")
+    /// });
+    ///
+    /// assert_eq!(parsed.result, ResultOption::Ok(42));
+    /// assert_eq!(parsed.warnings, ["deprecated key", "value should be quoted"]);
+    /// ```
+    pub fn and_then<U, F>(mut self, f: F) -> Diagnosed<U, E, W>
+    where
+        F: FnOnce(T) -> Diagnosed<U, E, W>,
+    {
+        let result = match self.result {
+            ResultOption::Ok(t) => {
+                let next = f(t);
+                self.warnings.extend(next.warnings);
+                next.result
+            }
+            ResultOption::None => ResultOption::None,
+            ResultOption::Err(e) => ResultOption::Err(e),
+        };
+        Diagnosed {
+            result,
+            warnings: self.warnings,
+        }
+    }
+
+    /// Removes all warnings, returning them as an iterator, oldest first.
+    #[inline]
+    pub fn drain_warnings(&mut self) -> std::vec::Drain<'_, W> {
+        self.warnings.drain(..)
+    }
+
+    /// Passes every warning to `log`, oldest first, and returns the outcome.
+    ///
+    /// This is typically called once at the boundary where warnings are reported.
+    #[inline]
+    pub fn finish<L: FnMut(W)>(self, log: L) -> ResultOption<T, E> {
+        self.warnings.into_iter().for_each(log);
+        self.result
+    }
+
+    /// Splits into the outcome and the warnings.
+    #[inline]
+    pub fn into_parts(self) -> (ResultOption<T, E>, Vec<W>) {
+        (self.result, self.warnings)
+    }
+}
+
+impl<T, E, W> From<ResultOption<T, E>> for Diagnosed<T, E, W> {
+    /// Wraps an outcome without any warnings.
+    #[inline]
+    fn from(result: ResultOption<T, E>) -> Self {
+        Self::new(result)
+    }
+}
@@ -0,0 +1,109 @@
+use crate::ResultOption;
+use core::fmt::{self, Display, Formatter};
+
+impl<T: Display, E: Display> Display for ResultOption<T, E> {
+    /// Formats the `ResultOption` for end users.
+    ///
+    /// `Ok` renders its value, `None` renders as `none` and `Err` renders as
+    /// `error: ` followed by the error. Formatting options such as width and
+    /// alignment are applied to the `Ok` value and to `none`.
+    ///
+    /// Use [`ResultOption::display_with`] for a different rendering.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Ok(42);
+    /// assert_eq!(x.to_string(), "42");
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::None;
+    /// assert_eq!(x.to_string(), "none");
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Err("not found");
+    /// assert_eq!(x.to_string(), "error: not found");
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Ok(42);
+    /// assert_eq!(format!("[{x:>4}]"), "[  42]");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ok(t) => t.fmt(f),
+            Self::None => f.pad("none"),
+            Self::Err(e) => write!(f, "error: {e}"),
+        }
+    }
+}
+
+/// Helper struct for rendering a [`ResultOption`] with custom formatting.
+///
+/// Created by [`ResultOption::display_with`].
+#[must_use = "this does not display anything unless formatted"]
+pub struct DisplayWith<'a, T, E, O, R> {
+    inner: &'a ResultOption<T, E>,
+    ok_fmt: O,
+    none_text: &'a str,
+    err_fmt: R,
+}
+
+impl<T, E> ResultOption<T, E> {
+    /// Returns an object implementing [`Display`] that renders each variant the way you choose.
+    ///
+    /// `ok_fmt` and `err_fmt` write the `Ok` value and the `Err` value to the formatter,
+    /// while `None` is rendered as `none_text`. This avoids a `match` at every print site.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let rows: [ResultOption<u32, &str>; 3] = [
+    ///     ResultOption::Ok(42),
+    ///     ResultOption::None,
+    ///     ResultOption::Err("timeout"),
+    /// ];
+    ///
+    /// let rendered: Vec<String> = rows
+    ///     .iter()
+    ///     .map(|row| {
+    ///         row.display_with(|v, f| write!(f, "{v}"), "-", |e, f| write!(f, "error: {e}"))
+    ///             .to_string()
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(rendered, ["42", "-", "error: timeout"]);
+    /// ```
+    #[inline]
+    pub fn display_with<'a, O, R>(
+        &'a self,
+        ok_fmt: O,
+        none_text: &'a str,
+        err_fmt: R,
+    ) -> DisplayWith<'a, T, E, O, R>
+    where
+        O: Fn(&T, &mut Formatter<'_>) -> fmt::Result,
+        R: Fn(&E, &mut Formatter<'_>) -> fmt::Result,
+    {
+        DisplayWith {
+            inner: self,
+            ok_fmt,
+            none_text,
+            err_fmt,
+        }
+    }
+}
+
+impl<T, E, O, R> Display for DisplayWith<'_, T, E, O, R>
+where
+    O: Fn(&T, &mut Formatter<'_>) -> fmt::Result,
+    R: Fn(&E, &mut Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.inner {
+            ResultOption::Ok(t) => (self.ok_fmt)(t, f),
+            ResultOption::None => f.pad(self.none_text),
+            ResultOption::Err(e) => (self.err_fmt)(e, f),
+        }
+    }
+}
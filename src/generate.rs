@@ -0,0 +1,87 @@
+use crate::ResultOptionKind;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_support;
+#[cfg(feature = "proptest")]
+mod proptest_support;
+#[cfg(feature = "quickcheck")]
+mod quickcheck_support;
+
+/// Relative weights of the `Ok`, `None` and `Err` variants when generating random
+/// `ResultOption` values.
+///
+/// The [`Default`] weights generate each variant equally often. A weight of zero never
+/// generates that variant.
+///
+/// # Examples
+///
+/// ```
+/// use result_option::VariantWeights;
+///
+/// // Mostly `Ok`, occasionally `None`, rarely `Err`.
+/// let weights = VariantWeights::new(8, 3, 1);
+/// assert_eq!(weights.total(), 12);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct VariantWeights {
+    ok: u32,
+    none: u32,
+    err: u32,
+}
+
+impl VariantWeights {
+    /// Creates weights for the `Ok`, `None` and `Err` variants.
+    ///
+    /// # Panics
+    ///
+    /// Panics if all weights are zero or if their sum overflows a `u32`.
+    #[inline]
+    pub const fn new(ok: u32, none: u32, err: u32) -> Self {
+        match ok.checked_add(none) {
+            Some(sum) => match sum.checked_add(err) {
+                Some(0) => panic!("at least one variant weight must be non-zero"),
+                Some(_) => Self { ok, none, err },
+                None => panic!("variant weights overflow u32"),
+            },
+            None => panic!("variant weights overflow u32"),
+        }
+    }
+
+    /// Returns the weight of the given variant.
+    #[must_use]
+    #[inline]
+    pub const fn weight(&self, kind: ResultOptionKind) -> u32 {
+        match kind {
+            ResultOptionKind::Ok => self.ok,
+            ResultOptionKind::None => self.none,
+            ResultOptionKind::Err => self.err,
+        }
+    }
+
+    /// Returns the sum of all weights, which is never zero.
+    #[must_use]
+    #[inline]
+    pub const fn total(&self) -> u32 {
+        self.ok + self.none + self.err
+    }
+
+    /// Maps `roll`, in the range `0..self.total()`, to a variant.
+    #[cfg(any(feature = "quickcheck", feature = "arbitrary"))]
+    #[inline]
+    const fn select(&self, roll: u32) -> ResultOptionKind {
+        if roll < self.none {
+            ResultOptionKind::None
+        } else if roll < self.none + self.ok {
+            ResultOptionKind::Ok
+        } else {
+            ResultOptionKind::Err
+        }
+    }
+}
+
+impl Default for VariantWeights {
+    #[inline]
+    fn default() -> Self {
+        Self::new(1, 1, 1)
+    }
+}
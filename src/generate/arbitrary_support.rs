@@ -0,0 +1,49 @@
+use super::VariantWeights;
+use crate::{ResultOption, ResultOptionKind};
+use arbitrary::{Arbitrary, Result, Unstructured, size_hint};
+
+impl VariantWeights {
+    /// Builds a `ResultOption` with these weights from fuzzer input, with payloads from
+    /// the `Arbitrary` impls of `T` and `E`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::Unstructured;
+    /// use result_option::{ResultOption, VariantWeights};
+    ///
+    /// let mut u = Unstructured::new(&[7, 42, 0, 0, 0]);
+    /// let x: ResultOption<u8, String> = VariantWeights::new(1, 0, 0).arbitrary(&mut u)?;
+    /// assert!(x.is_ok());
+    /// # Ok::<(), arbitrary::Error>(())
+    /// ```
+    pub fn arbitrary<'a, T, E>(&self, u: &mut Unstructured<'a>) -> Result<ResultOption<T, E>>
+    where
+        T: Arbitrary<'a>,
+        E: Arbitrary<'a>,
+    {
+        Ok(match self.select(u.int_in_range(0..=self.total() - 1)?) {
+            ResultOptionKind::Ok => ResultOption::Ok(T::arbitrary(u)?),
+            ResultOptionKind::None => ResultOption::None,
+            ResultOptionKind::Err => ResultOption::Err(E::arbitrary(u)?),
+        })
+    }
+}
+
+/// Builds all three variants with equal weights; use [`VariantWeights::arbitrary`] for
+/// other weights.
+impl<'a, T: Arbitrary<'a>, E: Arbitrary<'a>> Arbitrary<'a> for ResultOption<T, E> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        VariantWeights::default().arbitrary(u)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(
+            <u32 as Arbitrary>::size_hint(depth),
+            size_hint::or(
+                (0, Some(0)),
+                size_hint::or(T::size_hint(depth), E::size_hint(depth)),
+            ),
+        )
+    }
+}
@@ -0,0 +1,92 @@
+use super::VariantWeights;
+use crate::{ResultOption, ResultOptionKind};
+use proptest::arbitrary::{Arbitrary, any_with};
+use proptest::strategy::{BoxedStrategy, LazyJust, Strategy, Union};
+use std::fmt::Debug;
+
+impl VariantWeights {
+    /// Returns a `proptest` strategy generating `ResultOption` values with these weights,
+    /// drawing the payloads from `ok` and `err`.
+    ///
+    /// Values shrink towards `None`, then towards `Ok`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use proptest::prelude::*;
+    /// use proptest::test_runner::TestRunner;
+    /// use result_option::VariantWeights;
+    ///
+    /// let strategy = VariantWeights::new(1, 1, 0).strategy(0..100u32, any::<String>());
+    /// TestRunner::default()
+    ///     .run(&strategy, |x| {
+    ///         prop_assert!(!x.is_err());
+    ///         Ok(())
+    ///     })
+    ///     .unwrap();
+    /// ```
+    pub fn strategy<T, E, S, F>(self, ok: S, err: F) -> BoxedStrategy<ResultOption<T, E>>
+    where
+        T: Debug + 'static,
+        E: Debug + 'static,
+        S: Strategy<Value = T> + 'static,
+        F: Strategy<Value = E> + 'static,
+    {
+        let variants = [
+            (
+                self.weight(ResultOptionKind::None),
+                LazyJust::new(|| ResultOption::None).boxed(),
+            ),
+            (
+                self.weight(ResultOptionKind::Ok),
+                ok.prop_map(ResultOption::Ok).boxed(),
+            ),
+            (
+                self.weight(ResultOptionKind::Err),
+                err.prop_map(ResultOption::Err).boxed(),
+            ),
+        ];
+        Union::new_weighted(variants.into_iter().filter(|&(w, _)| w > 0).collect()).boxed()
+    }
+}
+
+/// Generates all three variants, weighted by the [`VariantWeights`] parameter, with
+/// payloads from the `Arbitrary` impls of `T` and `E`.
+///
+/// # Examples
+///
+/// ```
+/// use proptest::prelude::*;
+/// use proptest::test_runner::TestRunner;
+/// use result_option::{ResultOption, VariantWeights};
+///
+/// let mut runner = TestRunner::default();
+/// runner
+///     .run(&any::<ResultOption<u8, bool>>(), |x| {
+///         prop_assert_eq!(x.is_ok(), x.ok().is_some());
+///         Ok(())
+///     })
+///     .unwrap();
+///
+/// let weights = VariantWeights::new(1, 0, 1);
+/// runner
+///     .run(&any_with::<ResultOption<u8, bool>>((weights, (), ())), |x| {
+///         prop_assert!(!x.is_none());
+///         Ok(())
+///     })
+///     .unwrap();
+/// ```
+impl<T, E> Arbitrary for ResultOption<T, E>
+where
+    T: Arbitrary + 'static,
+    E: Arbitrary + 'static,
+    T::Strategy: 'static,
+    E::Strategy: 'static,
+{
+    type Parameters = (VariantWeights, T::Parameters, E::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((weights, ok, err): Self::Parameters) -> Self::Strategy {
+        weights.strategy(any_with::<T>(ok), any_with::<E>(err))
+    }
+}
@@ -0,0 +1,98 @@
+use super::VariantWeights;
+use crate::{ResultOption, ResultOptionKind};
+use quickcheck::{Arbitrary, Gen};
+
+/// Every byte value, for drawing uniform bytes with `Gen::choose`.
+const BYTES: [u8; 256] = {
+    let mut bytes = [0; 256];
+    let mut i = 0;
+    while i < bytes.len() {
+        bytes[i] = i as u8;
+        i += 1;
+    }
+    bytes
+};
+
+/// Draws a uniform `u32` in `0..bound`.
+///
+/// `u32::arbitrary` favours `MIN`, `1` and `MAX`, so the number is built from uniform
+/// bytes instead, rejecting draws past the largest multiple of `bound` to avoid bias.
+fn uniform_below(g: &mut Gen, bound: u32) -> u32 {
+    let zone = u32::MAX - u32::MAX % bound;
+    loop {
+        let bytes = [(); 4].map(|()| *g.choose(&BYTES).expect("`BYTES` is not empty"));
+        let x = u32::from_le_bytes(bytes);
+        if x < zone {
+            return x % bound;
+        }
+    }
+}
+
+impl VariantWeights {
+    /// Generates a `ResultOption` with these weights for `quickcheck`, with payloads from
+    /// the `Arbitrary` impls of `T` and `E`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickcheck::Gen;
+    /// use result_option::{ResultOption, VariantWeights};
+    ///
+    /// let mut g = Gen::new(10);
+    /// let x: ResultOption<u8, String> = VariantWeights::new(1, 0, 0).generate(&mut g);
+    /// assert!(x.is_ok());
+    /// ```
+    ///
+    /// The variants are drawn in proportion to their weights:
+    ///
+    /// ```
+    /// use quickcheck::Gen;
+    /// use result_option::{ResultOption, ResultOptionKind, VariantWeights};
+    ///
+    /// let mut g = Gen::from_size_and_seed(10, 42);
+    /// let share = |weights: VariantWeights, g: &mut Gen, kind: ResultOptionKind| {
+    ///     let draws = 30_000;
+    ///     let hits = (0..draws)
+    ///         .filter(|_| weights.generate::<u8, u8>(g).kind() == kind)
+    ///         .count();
+    ///     hits as f64 / draws as f64
+    /// };
+    ///
+    /// let rare_none = share(VariantWeights::new(98, 1, 1), &mut g, ResultOptionKind::None);
+    /// assert!((0.005..0.015).contains(&rare_none), "{rare_none}");
+    ///
+    /// for kind in [ResultOptionKind::Ok, ResultOptionKind::None, ResultOptionKind::Err] {
+    ///     let even = share(VariantWeights::default(), &mut g, kind);
+    ///     assert!((0.32..0.347).contains(&even), "{kind:?}: {even}");
+    /// }
+    /// ```
+    pub fn generate<T: Arbitrary, E: Arbitrary>(&self, g: &mut Gen) -> ResultOption<T, E> {
+        match self.select(uniform_below(g, self.total())) {
+            ResultOptionKind::Ok => ResultOption::Ok(T::arbitrary(g)),
+            ResultOptionKind::None => ResultOption::None,
+            ResultOptionKind::Err => ResultOption::Err(E::arbitrary(g)),
+        }
+    }
+}
+
+/// Generates all three variants equally often; use [`VariantWeights::generate`] for
+/// other weights.
+///
+/// `Ok` and `Err` values shrink to `None`, then to shrunk payloads.
+impl<T: Arbitrary, E: Arbitrary> Arbitrary for ResultOption<T, E> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        VariantWeights::default().generate(g)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self {
+            ResultOption::Ok(t) => Box::new(
+                std::iter::once(ResultOption::None).chain(t.shrink().map(ResultOption::Ok)),
+            ),
+            ResultOption::None => quickcheck::empty_shrinker(),
+            ResultOption::Err(e) => Box::new(
+                std::iter::once(ResultOption::None).chain(e.shrink().map(ResultOption::Err)),
+            ),
+        }
+    }
+}
@@ -0,0 +1,553 @@
+use crate::ResultOption;
+use core::iter::FusedIterator;
+
+/// Extension methods for iterators yielding [`ResultOption`] items.
+///
+/// Implemented for every `Iterator<Item = ResultOption<T, E>>`.
+///
+/// # Examples
+///
+/// ```
+/// use result_option::{ResultOption, ResultOptionIteratorExt};
+///
+/// let lookups: Vec<ResultOption<u32, &str>> = vec![
+///     ResultOption::Ok(1),
+///     ResultOption::None,
+///     ResultOption::Err("timeout"),
+///     ResultOption::Ok(4),
+/// ];
+///
+/// let doubled: Vec<u32> = lookups.iter().copied().map_ok(|v| v * 2).ok_values().collect();
+/// assert_eq!(doubled, [2, 8]);
+///
+/// let errors: Vec<&str> = lookups.into_iter().errors().collect();
+/// assert_eq!(errors, ["timeout"]);
+/// ```
+pub trait ResultOptionIteratorExt<T, E>: Iterator<Item = ResultOption<T, E>> + Sized {
+    /// Maps every `Ok` value with `f`, passing `None` and `Err` items through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ResultOption, ResultOptionIteratorExt};
+    ///
+    /// let items = [ResultOption::Ok(1), ResultOption::None, ResultOption::Err("error")];
+    /// let mapped: Vec<_> = items.into_iter().map_ok(|v| v + 1).collect();
+    /// assert_eq!(mapped, [ResultOption::Ok(2), ResultOption::None, ResultOption::Err("error")]);
+    /// ```
+    #[inline]
+    fn map_ok<U, F: FnMut(T) -> U>(self, f: F) -> MapOk<Self, F> {
+        MapOk { iter: self, f }
+    }
+
+    /// Replaces every `Ok` value with the result of `f`, passing `None` and `Err` items
+    /// through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ResultOption, ResultOptionIteratorExt};
+    ///
+    /// let items = [ResultOption::Ok(0), ResultOption::Ok(2), ResultOption::Err("error")];
+    /// let inverted: Vec<_> = items
+    ///     .into_iter()
+    ///     .and_then_ok(|v| if v == 0 { ResultOption::None } else { ResultOption::Ok(10 / v) })
+    ///     .collect();
+    /// assert_eq!(inverted, [ResultOption::None, ResultOption::Ok(5), ResultOption::Err("error")]);
+    /// ```
+    #[inline]
+    fn and_then_ok<U, F: FnMut(T) -> ResultOption<U, E>>(self, f: F) -> AndThenOk<Self, F> {
+        AndThenOk { iter: self, f }
+    }
+
+    /// Removes the `Ok` items whose value does not match `predicate`, keeping all
+    /// `None` and `Err` items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ResultOption, ResultOptionIteratorExt};
+    ///
+    /// let items = [ResultOption::Ok(1), ResultOption::Ok(2), ResultOption::None, ResultOption::Err("error")];
+    /// let even: Vec<_> = items.into_iter().filter_ok(|v| v % 2 == 0).collect();
+    /// assert_eq!(even, [ResultOption::Ok(2), ResultOption::None, ResultOption::Err("error")]);
+    /// ```
+    #[inline]
+    fn filter_ok<P: FnMut(&T) -> bool>(self, predicate: P) -> FilterOk<Self, P> {
+        FilterOk {
+            iter: self,
+            predicate,
+        }
+    }
+
+    /// Yields only the `Ok` values, skipping `None` and `Err` items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ResultOption, ResultOptionIteratorExt};
+    ///
+    /// let items = [ResultOption::Ok(1), ResultOption::None, ResultOption::Err("error"), ResultOption::Ok(2)];
+    /// let values: Vec<_> = items.into_iter().ok_values().collect();
+    /// assert_eq!(values, [1, 2]);
+    /// ```
+    #[inline]
+    fn ok_values(self) -> OkValues<Self> {
+        OkValues { iter: self }
+    }
+
+    /// Yields only the `Err` values, skipping `Ok` and `None` items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ResultOption, ResultOptionIteratorExt};
+    ///
+    /// let items = [ResultOption::Ok(1), ResultOption::Err("a"), ResultOption::None, ResultOption::Err("b")];
+    /// let errors: Vec<_> = items.into_iter().errors().collect();
+    /// assert_eq!(errors, ["a", "b"]);
+    /// ```
+    #[inline]
+    fn errors(self) -> Errors<Self> {
+        Errors { iter: self }
+    }
+
+    /// Returns the first `Ok` value matching `predicate`, stopping at the first `Err`.
+    ///
+    /// Returns `Ok` with the value if found before any `Err`, the `Err` if one comes
+    /// first, and `None` if the iterator is exhausted without a match. `None` items
+    /// are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ResultOption, ResultOptionIteratorExt};
+    ///
+    /// let items = [ResultOption::None, ResultOption::Ok(3), ResultOption::Err("error")];
+    /// assert_eq!(items.into_iter().try_find(|v| *v > 2), ResultOption::Ok(3));
+    /// assert_eq!(items.into_iter().try_find(|v| *v > 5), ResultOption::Err("error"));
+    ///
+    /// let items: [ResultOption<u32, &str>; 2] = [ResultOption::Ok(1), ResultOption::None];
+    /// assert_eq!(items.into_iter().try_find(|v| *v > 5), ResultOption::None);
+    /// ```
+    #[inline]
+    fn try_find<P: FnMut(&T) -> bool>(self, mut predicate: P) -> ResultOption<T, E> {
+        for item in self {
+            match item {
+                ResultOption::Ok(t) if predicate(&t) => return ResultOption::Ok(t),
+                ResultOption::Ok(_) | ResultOption::None => {}
+                ResultOption::Err(e) => return ResultOption::Err(e),
+            }
+        }
+        ResultOption::None
+    }
+
+    /// Folds the `Ok` values into an accumulator, stopping at the first `Err`.
+    ///
+    /// `None` items are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ResultOption, ResultOptionIteratorExt};
+    ///
+    /// let items = [ResultOption::Ok(1), ResultOption::None, ResultOption::Ok(2)];
+    /// assert_eq!(items.into_iter().try_fold_ok(0, |acc, v| acc + v), Ok::<_, &str>(3));
+    ///
+    /// let items = [ResultOption::Ok(1), ResultOption::Err("error"), ResultOption::Ok(2)];
+    /// assert_eq!(items.into_iter().try_fold_ok(0, |acc, v| acc + v), Err("error"));
+    /// ```
+    #[inline]
+    fn try_fold_ok<B, F: FnMut(B, T) -> B>(self, init: B, mut f: F) -> Result<B, E> {
+        let mut acc = init;
+        for item in self {
+            match item {
+                ResultOption::Ok(t) => acc = f(acc, t),
+                ResultOption::None => {}
+                ResultOption::Err(e) => return Err(e),
+            }
+        }
+        Ok(acc)
+    }
+
+    /// Returns the first `Err` value, consuming the iterator up to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ResultOption, ResultOptionIteratorExt};
+    ///
+    /// let items = [ResultOption::Ok(1), ResultOption::Err("a"), ResultOption::Err("b")];
+    /// assert_eq!(items.into_iter().first_err(), Some("a"));
+    ///
+    /// let items: [ResultOption<u32, &str>; 2] = [ResultOption::Ok(1), ResultOption::None];
+    /// assert_eq!(items.into_iter().first_err(), None);
+    /// ```
+    #[inline]
+    fn first_err(mut self) -> Option<E> {
+        self.find_map(ResultOption::err)
+    }
+
+    /// Splits the items into `Ok` values, a count of `None`s and `Err` values, in a single pass.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ResultOption, ResultOptionIteratorExt};
+    ///
+    /// let rows = [
+    ///     ResultOption::Ok(1),
+    ///     ResultOption::None,
+    ///     ResultOption::Err("bad row"),
+    ///     ResultOption::Ok(2),
+    ///     ResultOption::None,
+    /// ];
+    /// let (oks, nones, errs) = rows.into_iter().partition_ro();
+    /// assert_eq!(oks, [1, 2]);
+    /// assert_eq!(nones, 2);
+    /// assert_eq!(errs, ["bad row"]);
+    /// ```
+    #[inline]
+    fn partition_ro(self) -> (Vec<T>, usize, Vec<E>) {
+        self.partition_ro_into()
+    }
+
+    /// Splits the items into collections of `Ok` and `Err` values and a count of `None`s,
+    /// in a single pass.
+    ///
+    /// This is the generic version of [`partition_ro`](ResultOptionIteratorExt::partition_ro),
+    /// filling any collections that implement `Default` and `Extend`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ResultOption, ResultOptionIteratorExt};
+    /// use std::collections::BTreeSet;
+    ///
+    /// let rows = [
+    ///     ResultOption::Ok(2),
+    ///     ResultOption::Err("bad row"),
+    ///     ResultOption::Ok(1),
+    ///     ResultOption::Ok(2),
+    ///     ResultOption::None,
+    /// ];
+    /// let (oks, nones, errs): (BTreeSet<_>, _, Vec<_>) = rows.into_iter().partition_ro_into();
+    /// assert_eq!(oks, BTreeSet::from([1, 2]));
+    /// assert_eq!(nones, 1);
+    /// assert_eq!(errs, ["bad row"]);
+    /// ```
+    fn partition_ro_into<A, B>(self) -> (A, usize, B)
+    where
+        A: Default + Extend<T>,
+        B: Default + Extend<E>,
+    {
+        let mut oks = A::default();
+        let mut nones = 0;
+        let mut errs = B::default();
+        for item in self {
+            match item {
+                ResultOption::Ok(t) => oks.extend(Some(t)),
+                ResultOption::None => nones += 1,
+                ResultOption::Err(e) => errs.extend(Some(e)),
+            }
+        }
+        (oks, nones, errs)
+    }
+
+    /// Splits the items into `Ok` values, `None`s and `Err` values, keeping the position
+    /// of each item in the original iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ResultOption, ResultOptionIteratorExt};
+    ///
+    /// let rows = [
+    ///     ResultOption::Ok(1),
+    ///     ResultOption::None,
+    ///     ResultOption::Err("bad row"),
+    ///     ResultOption::Ok(2),
+    /// ];
+    /// let (oks, nones, errs) = rows.into_iter().partition_ro_indexed();
+    /// assert_eq!(oks, [(0, 1), (3, 2)]);
+    /// assert_eq!(nones, [1]);
+    /// assert_eq!(errs, [(2, "bad row")]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn partition_ro_indexed(self) -> (Vec<(usize, T)>, Vec<usize>, Vec<(usize, E)>) {
+        let mut oks = Vec::new();
+        let mut nones = Vec::new();
+        let mut errs = Vec::new();
+        for (index, item) in self.enumerate() {
+            match item {
+                ResultOption::Ok(t) => oks.push((index, t)),
+                ResultOption::None => nones.push(index),
+                ResultOption::Err(e) => errs.push((index, e)),
+            }
+        }
+        (oks, nones, errs)
+    }
+
+    /// Flattens `Ok` values that are themselves iterable, yielding one `Ok` per inner item.
+    ///
+    /// `None` and `Err` items are passed through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ResultOption, ResultOptionIteratorExt};
+    ///
+    /// let items = [ResultOption::Ok(vec![1, 2]), ResultOption::None, ResultOption::Err("error")];
+    /// let flat: Vec<_> = items.into_iter().flatten_ok().collect();
+    /// assert_eq!(
+    ///     flat,
+    ///     [ResultOption::Ok(1), ResultOption::Ok(2), ResultOption::None, ResultOption::Err("error")]
+    /// );
+    /// ```
+    #[inline]
+    fn flatten_ok(self) -> FlattenOk<Self, T::IntoIter>
+    where
+        T: IntoIterator,
+    {
+        FlattenOk {
+            iter: self,
+            inner: None,
+        }
+    }
+}
+
+impl<I, T, E> ResultOptionIteratorExt<T, E> for I where I: Iterator<Item = ResultOption<T, E>> {}
+
+impl<T, E, C: FromIterator<T>> FromIterator<ResultOption<T, E>> for ResultOption<C, E> {
+    /// Collects the `Ok` values into a collection.
+    ///
+    /// Returns the first `Err` if there is any. Otherwise, returns `None` if any item is
+    /// `None`, or `Ok` with the collection if all items are `Ok`. Iteration stops at the
+    /// first `Err`, but continues past `None` items to look for one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let items = [ResultOption::Ok(1), ResultOption::Ok(2)];
+    /// let all: ResultOption<Vec<u32>, &str> = items.into_iter().collect();
+    /// assert_eq!(all, ResultOption::Ok(vec![1, 2]));
+    ///
+    /// let items = [ResultOption::Ok(1), ResultOption::None];
+    /// let all: ResultOption<Vec<u32>, &str> = items.into_iter().collect();
+    /// assert_eq!(all, ResultOption::None);
+    ///
+    /// let items = [ResultOption::None, ResultOption::Err("error"), ResultOption::Ok(1)];
+    /// let all: ResultOption<Vec<u32>, &str> = items.into_iter().collect();
+    /// assert_eq!(all, ResultOption::Err("error"));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = ResultOption<T, E>>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let mut none_seen = false;
+        let mut error = None;
+        let collection = core::iter::from_fn(|| {
+            loop {
+                match iter.next()? {
+                    ResultOption::Ok(t) => return Some(t),
+                    ResultOption::None => none_seen = true,
+                    ResultOption::Err(e) => {
+                        error = Some(e);
+                        return None;
+                    }
+                }
+            }
+        })
+        .collect();
+        match error {
+            Some(e) => ResultOption::Err(e),
+            None if none_seen => ResultOption::None,
+            None => ResultOption::Ok(collection),
+        }
+    }
+}
+
+/// Iterator adapter created by [`ResultOptionIteratorExt::map_ok`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapOk<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F, T, U, E> Iterator for MapOk<I, F>
+where
+    I: Iterator<Item = ResultOption<T, E>>,
+    F: FnMut(T) -> U,
+{
+    type Item = ResultOption<U, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|item| item.map(&mut self.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F, T, U, E> FusedIterator for MapOk<I, F>
+where
+    I: FusedIterator<Item = ResultOption<T, E>>,
+    F: FnMut(T) -> U,
+{
+}
+
+/// Iterator adapter created by [`ResultOptionIteratorExt::and_then_ok`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct AndThenOk<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F, T, U, E> Iterator for AndThenOk<I, F>
+where
+    I: Iterator<Item = ResultOption<T, E>>,
+    F: FnMut(T) -> ResultOption<U, E>,
+{
+    type Item = ResultOption<U, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|item| item.and_then(&mut self.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F, T, U, E> FusedIterator for AndThenOk<I, F>
+where
+    I: FusedIterator<Item = ResultOption<T, E>>,
+    F: FnMut(T) -> ResultOption<U, E>,
+{
+}
+
+/// Iterator adapter created by [`ResultOptionIteratorExt::filter_ok`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FilterOk<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<I, P, T, E> Iterator for FilterOk<I, P>
+where
+    I: Iterator<Item = ResultOption<T, E>>,
+    P: FnMut(&T) -> bool,
+{
+    type Item = ResultOption<T, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let predicate = &mut self.predicate;
+        self.iter.find(|item| match item {
+            ResultOption::Ok(t) => predicate(t),
+            ResultOption::None | ResultOption::Err(_) => true,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, P, T, E> FusedIterator for FilterOk<I, P>
+where
+    I: FusedIterator<Item = ResultOption<T, E>>,
+    P: FnMut(&T) -> bool,
+{
+}
+
+/// Iterator adapter created by [`ResultOptionIteratorExt::ok_values`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OkValues<I> {
+    iter: I,
+}
+
+impl<I, T, E> Iterator for OkValues<I>
+where
+    I: Iterator<Item = ResultOption<T, E>>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.find_map(ResultOption::ok)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, T, E> FusedIterator for OkValues<I> where I: FusedIterator<Item = ResultOption<T, E>> {}
+
+/// Iterator adapter created by [`ResultOptionIteratorExt::errors`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Errors<I> {
+    iter: I,
+}
+
+impl<I, T, E> Iterator for Errors<I>
+where
+    I: Iterator<Item = ResultOption<T, E>>,
+{
+    type Item = E;
+
+    #[inline]
+    fn next(&mut self) -> Option<E> {
+        self.iter.find_map(ResultOption::err)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, T, E> FusedIterator for Errors<I> where I: FusedIterator<Item = ResultOption<T, E>> {}
+
+/// Iterator adapter created by [`ResultOptionIteratorExt::flatten_ok`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FlattenOk<I, J> {
+    iter: I,
+    inner: Option<J>,
+}
+
+impl<I, T, E> Iterator for FlattenOk<I, T::IntoIter>
+where
+    I: Iterator<Item = ResultOption<T, E>>,
+    T: IntoIterator,
+{
+    type Item = ResultOption<T::Item, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.inner.as_mut().and_then(Iterator::next) {
+                return Some(ResultOption::Ok(item));
+            }
+            self.inner = None;
+            match self.iter.next()? {
+                ResultOption::Ok(t) => self.inner = Some(t.into_iter()),
+                ResultOption::None => return Some(ResultOption::None),
+                ResultOption::Err(e) => return Some(ResultOption::Err(e)),
+            }
+        }
+    }
+}
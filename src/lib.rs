@@ -15,7 +15,151 @@ pub enum ResultOption<T, E> {
     Err(E),
 }
 
+/// The variant of a [`ResultOption`], without its payload.
+///
+/// Returned by [`ResultOption::kind`]. Being fieldless and `Copy`, it is cheap to
+/// match on, count, or use as a map key.
+///
+/// # Examples
+///
+/// ```
+/// use result_option::{ResultOption, ResultOptionKind};
+/// use std::collections::BTreeMap;
+///
+/// let lookups: [ResultOption<u32, &str>; 4] = [
+///     ResultOption::Ok(1),
+///     ResultOption::None,
+///     ResultOption::Ok(2),
+///     ResultOption::Err("timeout"),
+/// ];
+///
+/// let mut counts = BTreeMap::new();
+/// for lookup in &lookups {
+///     *counts.entry(lookup.kind()).or_insert(0) += 1;
+/// }
+/// assert_eq!(counts[&ResultOptionKind::Ok], 2);
+/// assert_eq!(counts[&ResultOptionKind::None], 1);
+/// assert_eq!(counts[&ResultOptionKind::Err], 1);
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+pub enum ResultOptionKind {
+    /// Corresponds to [`ResultOption::Ok`]
+    Ok,
+    /// Corresponds to [`ResultOption::None`]
+    None,
+    /// Corresponds to [`ResultOption::Err`]
+    Err,
+}
+
+impl ResultOptionKind {
+    /// Returns the lowercase name of the variant: `"ok"`, `"none"` or `"err"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOptionKind;
+    ///
+    /// assert_eq!(ResultOptionKind::Ok.as_str(), "ok");
+    /// assert_eq!(ResultOptionKind::None.as_str(), "none");
+    /// assert_eq!(ResultOptionKind::Err.as_str(), "err");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::None => "none",
+            Self::Err => "err",
+        }
+    }
+}
+
 impl<T, E> ResultOption<T, E> {
+    /// Builds a `ResultOption` from a closure returning `Result<Option<T>, E>`.
+    ///
+    /// This lets the closure body use the `?` operator on `Result`s and still produce
+    /// all three variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    /// use std::collections::BTreeMap;
+    /// use std::num::ParseIntError;
+    ///
+    /// let mut names = BTreeMap::new();
+    /// names.insert(7, "seven");
+    ///
+    /// let lookup = |key: &str| -> ResultOption<&str, ParseIntError> {
+    ///     ResultOption::from_fn(|| {
+    ///         let id: u32 = key.parse()?;
+    ///         Ok(names.get(&id).copied())
+    ///     })
+    /// };
+    ///
+    /// assert_eq!(lookup("7"), ResultOption::Ok("seven"));
+    /// assert_eq!(lookup("8"), ResultOption::None);
+    /// assert!(lookup("x").is_err());
+    /// ```
+    #[inline]
+    pub fn from_fn<F: FnOnce() -> Result<Option<T>, E>>(f: F) -> Self {
+        f().into()
+    }
+
+    /// Returns `Ok` with the value computed by `f` if `cond` is `true`, otherwise `None`.
+    ///
+    /// This is the `ResultOption` counterpart of [`bool::then`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::from_bool(true, || 2);
+    /// assert_eq!(x, ResultOption::Ok(2));
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::from_bool(false, || 2);
+    /// assert_eq!(x, ResultOption::None);
+    /// ```
+    #[inline]
+    pub fn from_bool<F: FnOnce() -> T>(cond: bool, f: F) -> Self {
+        if cond { Self::Ok(f()) } else { Self::None }
+    }
+
+    /// Converts a `Result<T, E>` into a `ResultOption<T, E>`, turning the errors
+    /// selected by `is_absent` into `None`.
+    ///
+    /// This is handy for APIs that report a missing value as an error, such as
+    /// [`std::io::ErrorKind::NotFound`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// let is_absent = |e: &Error| e.kind() == ErrorKind::NotFound;
+    ///
+    /// let x = ResultOption::from_result_if(Ok(2), is_absent);
+    /// assert_eq!(x.ok(), Some(2));
+    ///
+    /// let x: ResultOption<u32, Error> =
+    ///     ResultOption::from_result_if(Err(ErrorKind::NotFound.into()), is_absent);
+    /// assert!(x.is_none());
+    ///
+    /// let x: ResultOption<u32, Error> =
+    ///     ResultOption::from_result_if(Err(ErrorKind::PermissionDenied.into()), is_absent);
+    /// assert!(x.is_err());
+    /// ```
+    #[inline]
+    pub fn from_result_if<F: FnOnce(&E) -> bool>(result: Result<T, E>, is_absent: F) -> Self {
+        match result {
+            Ok(t) => Self::Ok(t),
+            Err(e) if is_absent(&e) => Self::None,
+            Err(e) => Self::Err(e),
+        }
+    }
+
     /// Returns `true` if the result is `Ok`.
     #[must_use]
     #[inline]
@@ -57,6 +201,214 @@ impl<T, E> ResultOption<T, E> {
         }
     }
 
+    /// Returns `true` if the result is `Ok` and the value inside it matches a predicate.
+    ///
+    /// Unlike [`is_ok_and`], this borrows `self`, so the value can still be used afterwards.
+    ///
+    /// [`is_ok_and`]: ResultOption::is_ok_and
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<String, &str> = ResultOption::Ok("hello".to_string());
+    /// assert!(x.is_ok_and_ref(|s| s.len() == 5));
+    /// assert_eq!(x, ResultOption::Ok("hello".to_string())); // `x` is still usable
+    ///
+    /// let x: ResultOption<String, &str> = ResultOption::None;
+    /// assert!(!x.is_ok_and_ref(|s| s.len() == 5));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_ok_and_ref(&self, f: impl FnOnce(&T) -> bool) -> bool {
+        match self {
+            Self::Ok(t) => f(t),
+            Self::None | Self::Err(_) => false,
+        }
+    }
+
+    /// Returns `true` if the result is `Err` and the error inside it matches a predicate.
+    ///
+    /// Unlike [`is_err_and`], this borrows `self`, so the value can still be used afterwards.
+    ///
+    /// [`is_err_and`]: ResultOption::is_err_and
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<u32, String> = ResultOption::Err("timeout".to_string());
+    /// assert!(x.is_err_and_ref(|e| e.starts_with("time")));
+    ///
+    /// let x: ResultOption<u32, String> = ResultOption::Ok(2);
+    /// assert!(!x.is_err_and_ref(|e| e.starts_with("time")));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_err_and_ref(&self, f: impl FnOnce(&E) -> bool) -> bool {
+        match self {
+            Self::Err(e) => f(e),
+            Self::None | Self::Ok(_) => false,
+        }
+    }
+
+    /// Returns `true` if the result is `None`, or `Ok` with a value matching a predicate.
+    ///
+    /// `Err` always yields `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Ok(2);
+    /// assert!(x.is_none_or(|v| v > 1));
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Ok(0);
+    /// assert!(!x.is_none_or(|v| v > 1));
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::None;
+    /// assert!(x.is_none_or(|v| v > 1));
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Err("error");
+    /// assert!(!x.is_none_or(|v| v > 1));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_none_or(self, f: impl FnOnce(T) -> bool) -> bool {
+        match self {
+            Self::Ok(t) => f(t),
+            Self::None => true,
+            Self::Err(_) => false,
+        }
+    }
+
+    /// Returns `true` if the result is `Ok` or `None`, i.e. not an `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Ok(2);
+    /// assert!(x.is_ok_or_none());
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::None;
+    /// assert!(x.is_ok_or_none());
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Err("error");
+    /// assert!(!x.is_ok_or_none());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_ok_or_none(&self) -> bool {
+        !self.is_err()
+    }
+
+    /// Returns `true` if the result is not an `Err`.
+    ///
+    /// This is the same as [`is_ok_or_none`](ResultOption::is_ok_or_none).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::None;
+    /// assert!(x.is_not_err());
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Err("error");
+    /// assert!(!x.is_not_err());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_not_err(&self) -> bool {
+        self.is_ok_or_none()
+    }
+
+    /// Returns `true` if the result is an `Ok` value equal to the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Ok(2);
+    /// assert!(x.contains(&2));
+    /// assert!(!x.contains(&3));
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::None;
+    /// assert!(!x.contains(&2));
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Err("error");
+    /// assert!(!x.contains(&2));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn contains<U>(&self, x: &U) -> bool
+    where
+        U: PartialEq<T>,
+    {
+        match self {
+            Self::Ok(t) => x == t,
+            Self::None | Self::Err(_) => false,
+        }
+    }
+
+    /// Returns `true` if the result is an `Err` value equal to the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Err("timeout");
+    /// assert!(x.contains_err(&"timeout"));
+    /// assert!(!x.contains_err(&"not found"));
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Ok(2);
+    /// assert!(!x.contains_err(&"timeout"));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn contains_err<F>(&self, f: &F) -> bool
+    where
+        F: PartialEq<E>,
+    {
+        match self {
+            Self::Err(e) => f == e,
+            Self::None | Self::Ok(_) => false,
+        }
+    }
+
+    /// Returns the variant of the result as a [`ResultOptionKind`], without its payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ResultOption, ResultOptionKind};
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Ok(2);
+    /// assert_eq!(x.kind(), ResultOptionKind::Ok);
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::None;
+    /// assert_eq!(x.kind(), ResultOptionKind::None);
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Err("error");
+    /// assert_eq!(x.kind(), ResultOptionKind::Err);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> ResultOptionKind {
+        match self {
+            Self::Ok(_) => ResultOptionKind::Ok,
+            Self::None => ResultOptionKind::None,
+            Self::Err(_) => ResultOptionKind::Err,
+        }
+    }
+
     /// Converts from `ResultOption<T, E>` to `Option<T>`, discarding the error if any.
     #[must_use]
     #[inline]
@@ -106,6 +458,35 @@ impl<T, E> ResultOption<T, E> {
         }
     }
 
+    /// Calls `f` with the `Ok` value and returns its result, leaving `None` and `Err` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let half = |x: u32| -> ResultOption<u32, &str> {
+    ///     match x {
+    ///         0 => ResultOption::None,
+    ///         x if x % 2 == 1 => ResultOption::Err("odd"),
+    ///         x => ResultOption::Ok(x / 2),
+    ///     }
+    /// };
+    ///
+    /// assert_eq!(ResultOption::Ok(4).and_then(half), ResultOption::Ok(2));
+    /// assert_eq!(ResultOption::Ok(3).and_then(half), ResultOption::Err("odd"));
+    /// assert_eq!(ResultOption::Ok(0).and_then(half), ResultOption::None);
+    /// assert_eq!(ResultOption::None.and_then(half), ResultOption::None);
+    /// ```
+    #[inline]
+    pub fn and_then<U, F: FnOnce(T) -> ResultOption<U, E>>(self, f: F) -> ResultOption<U, E> {
+        match self {
+            Self::Ok(t) => f(t),
+            Self::None => ResultOption::None,
+            Self::Err(e) => ResultOption::Err(e),
+        }
+    }
+
     /// Maps an `Ok` value using the provided function, or returns a default value if `None` or `Err`.
     #[must_use]
     #[inline]
@@ -270,14 +651,26 @@ impl<T, E> ResultOption<T, E> {
     /// let x: ResultOption<u32, &str> = ResultOption::Err("error");
     /// let y = unsafe { x.unwrap_unchecked() }; // undefined behavior!
     /// ```
+    ///
+    /// It can also be used in `const` contexts:
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// const X: u32 = unsafe { ResultOption::<u32, &str>::Ok(2).unwrap_unchecked() };
+    /// assert_eq!(X, 2);
+    /// ```
     #[inline]
-    pub unsafe fn unwrap_unchecked(self) -> T {
+    pub const unsafe fn unwrap_unchecked(self) -> T {
         debug_assert!(self.is_ok());
-        match self {
-            Self::Ok(t) => t,
+        let t = match &self {
+            // SAFETY: `self` is forgotten below, so the value is moved out exactly once.
+            Self::Ok(t) => unsafe { core::ptr::read(t) },
             // SAFETY: the safety contract must be upheld by the caller.
             Self::None | Self::Err(_) => unsafe { core::hint::unreachable_unchecked() },
-        }
+        };
+        core::mem::forget(self);
+        t
     }
 
     /// Returns the contained `Ok` value or a provided default.
@@ -479,14 +872,26 @@ impl<T, E> ResultOption<T, E> {
     /// let x: ResultOption<u32, &str> = ResultOption::None;
     /// let y = unsafe { x.unwrap_err_unchecked() }; // undefined behavior!
     /// ```
+    ///
+    /// It can also be used in `const` contexts:
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// const E: &str = unsafe { ResultOption::<u32, &str>::Err("error").unwrap_err_unchecked() };
+    /// assert_eq!(E, "error");
+    /// ```
     #[inline]
-    pub unsafe fn unwrap_err_unchecked(self) -> E {
+    pub const unsafe fn unwrap_err_unchecked(self) -> E {
         debug_assert!(self.is_err());
-        match self {
-            Self::Err(e) => e,
+        let e = match &self {
+            // SAFETY: `self` is forgotten below, so the error is moved out exactly once.
+            Self::Err(e) => unsafe { core::ptr::read(e) },
             // SAFETY: the safety contract must be upheld by the caller.
             Self::Ok(_) | Self::None => unsafe { core::hint::unreachable_unchecked() },
-        }
+        };
+        core::mem::forget(self);
+        e
     }
 
     /// Unwraps a `ResultOption`, returning `Some` value if `Ok`, `None` if `None`, or panicking if `Err`.
@@ -602,15 +1007,27 @@ impl<T, E> ResultOption<T, E> {
     /// let x: ResultOption<u32, &str> = ResultOption::Err("error");
     /// let y = unsafe { x.unwrap_option_unchecked() }; // undefined behavior!
     /// ```
+    ///
+    /// It can also be used in `const` contexts:
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// const X: Option<u32> = unsafe { ResultOption::<u32, &str>::None.unwrap_option_unchecked() };
+    /// assert_eq!(X, None);
+    /// ```
     #[inline]
-    pub unsafe fn unwrap_option_unchecked(self) -> Option<T> {
+    pub const unsafe fn unwrap_option_unchecked(self) -> Option<T> {
         debug_assert!(!self.is_err());
-        match self {
-            Self::Ok(t) => Some(t),
+        let o = match &self {
+            // SAFETY: `self` is forgotten below, so the value is moved out exactly once.
+            Self::Ok(t) => Some(unsafe { core::ptr::read(t) }),
             Self::None => None,
             // SAFETY: the safety contract must be upheld by the caller.
             Self::Err(_) => unsafe { core::hint::unreachable_unchecked() },
-        }
+        };
+        core::mem::forget(self);
+        o
     }
 
     /// Converts to `Option<T>`, providing a default value for `Err` cases.
@@ -708,6 +1125,223 @@ impl<T, E> ResultOption<T, E> {
     }
 }
 
+/// `const` counterparts of the extracting methods, available when both payloads are `Copy`.
+///
+/// The methods in the generic `impl` block cannot be `const` on stable Rust: a generic
+/// `const fn` cannot drop a value whose type may have a destructor, and those methods drop
+/// the discarded payload or format the `Err` payload into the panic message. `Copy` types
+/// have no destructor, so restricting `T` and `E` to `Copy` lifts the first limitation;
+/// the panicking methods here report only the variant or your message.
+///
+/// # Examples
+///
+/// Validating a lookup table at compile time:
+///
+/// ```
+/// use result_option::ResultOption;
+///
+/// const TABLE: [ResultOption<u8, &str>; 3] = [
+///     ResultOption::Ok(1),
+///     ResultOption::None,
+///     ResultOption::Err("reserved"),
+/// ];
+///
+/// const FIRST: u8 = TABLE[0].const_unwrap();
+/// const SECOND: u8 = TABLE[1].const_unwrap_or(0);
+/// const THIRD: Option<&str> = TABLE[2].const_err();
+/// const _: () = assert!(TABLE[1].is_none());
+///
+/// assert_eq!(FIRST, 1);
+/// assert_eq!(SECOND, 0);
+/// assert_eq!(THIRD, Some("reserved"));
+/// ```
+impl<T: Copy, E: Copy> ResultOption<T, E> {
+    /// `const` version of [`unwrap`](ResultOption::unwrap), which cannot be `const` as it
+    /// formats the `Err` payload with `Debug`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is a `None` or `Err`. Unlike `unwrap`, the panic message does
+    /// not include the content of the `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// const X: u32 = ResultOption::<u32, &str>::Ok(2).const_unwrap();
+    /// assert_eq!(X, 2);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Err("emergency failure");
+    /// x.const_unwrap(); // panics
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub const fn const_unwrap(self) -> T {
+        match self {
+            Self::Ok(t) => t,
+            Self::None => panic!("called `ResultOption::const_unwrap()` on a `None` value"),
+            Self::Err(_) => panic!("called `ResultOption::const_unwrap()` on an `Err` value"),
+        }
+    }
+
+    /// `const` version of [`expect`](ResultOption::expect), which cannot be `const` as it
+    /// formats the `Err` payload with `Debug`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is a `None` or `Err`, with a panic message provided by you.
+    /// Unlike `expect`, the panic message does not include the content of the `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// const X: u32 = ResultOption::<u32, &str>::Ok(2).const_expect("the number should be present");
+    /// assert_eq!(X, 2);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::None;
+    /// x.const_expect("testing const_expect with None"); // panics with `testing const_expect with None`
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub const fn const_expect(self, msg: &str) -> T {
+        match self {
+            Self::Ok(t) => t,
+            Self::None | Self::Err(_) => panic!("{}", msg),
+        }
+    }
+
+    /// `const` version of [`unwrap_or`](ResultOption::unwrap_or), which cannot be `const` as
+    /// it drops either `default` or the `Err` payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// const X: u32 = ResultOption::<u32, &str>::Ok(99).const_unwrap_or(42);
+    /// const Y: u32 = ResultOption::<u32, &str>::None.const_unwrap_or(42);
+    /// const Z: u32 = ResultOption::<u32, &str>::Err("error").const_unwrap_or(42);
+    /// assert_eq!((X, Y, Z), (99, 42, 42));
+    /// ```
+    #[inline]
+    pub const fn const_unwrap_or(self, default: T) -> T {
+        match self {
+            Self::Ok(t) => t,
+            Self::None | Self::Err(_) => default,
+        }
+    }
+
+    /// `const` version of [`ok`](ResultOption::ok), which cannot be `const` as it drops the
+    /// `Err` payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// const X: Option<u32> = ResultOption::<u32, &str>::Ok(2).const_ok();
+    /// const Y: Option<u32> = ResultOption::<u32, &str>::Err("error").const_ok();
+    /// assert_eq!((X, Y), (Some(2), None));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn const_ok(self) -> Option<T> {
+        match self {
+            Self::Ok(t) => Some(t),
+            Self::None | Self::Err(_) => None,
+        }
+    }
+
+    /// `const` version of [`err`](ResultOption::err), which cannot be `const` as it drops the
+    /// `Ok` payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// const X: Option<&str> = ResultOption::<u32, &str>::Err("error").const_err();
+    /// const Y: Option<&str> = ResultOption::<u32, &str>::Ok(2).const_err();
+    /// assert_eq!((X, Y), (Some("error"), None));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn const_err(self) -> Option<E> {
+        match self {
+            Self::Err(e) => Some(e),
+            Self::None | Self::Ok(_) => None,
+        }
+    }
+
+    /// `const` version of [`unwrap_option`](ResultOption::unwrap_option), which cannot be
+    /// `const` as it formats the `Err` payload with `Debug`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is an `Err`. Unlike `unwrap_option`, the panic message does
+    /// not include the content of the `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// const X: Option<u32> = ResultOption::<u32, &str>::Ok(2).const_unwrap_option();
+    /// const Y: Option<u32> = ResultOption::<u32, &str>::None.const_unwrap_option();
+    /// assert_eq!((X, Y), (Some(2), None));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Err("emergency failure");
+    /// x.const_unwrap_option(); // panics
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub const fn const_unwrap_option(self) -> Option<T> {
+        match self {
+            Self::Ok(t) => Some(t),
+            Self::None => None,
+            Self::Err(_) => {
+                panic!("called `ResultOption::const_unwrap_option()` on an `Err` value")
+            }
+        }
+    }
+}
+
+impl<T, E> Default for ResultOption<T, E> {
+    /// Returns [`ResultOption::None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// #[derive(Default)]
+    /// struct Cache {
+    ///     last_lookup: ResultOption<u32, String>,
+    /// }
+    ///
+    /// assert_eq!(Cache::default().last_lookup, ResultOption::None);
+    /// ```
+    #[inline]
+    fn default() -> Self {
+        Self::None
+    }
+}
+
 impl<T, E> From<Result<Option<T>, E>> for ResultOption<T, E> {
     fn from(r: Result<Option<T>, E>) -> Self {
         match r {
@@ -718,6 +1352,87 @@ impl<T, E> From<Result<Option<T>, E>> for ResultOption<T, E> {
     }
 }
 
+impl<T, E> From<ResultOption<T, E>> for Result<Option<T>, E> {
+    /// Converts a `ResultOption<T, E>` back into the nested `Result<Option<T>, E>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Ok(2);
+    /// assert_eq!(Result::from(x), Ok(Some(2)));
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::None;
+    /// assert_eq!(Result::from(x), Ok(None));
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Err("error");
+    /// assert_eq!(Result::from(x), Err("error"));
+    /// ```
+    fn from(ro: ResultOption<T, E>) -> Self {
+        match ro {
+            ResultOption::Ok(t) => Ok(Some(t)),
+            ResultOption::None => Ok(None),
+            ResultOption::Err(e) => Err(e),
+        }
+    }
+}
+
+impl<T, E> From<Option<Result<T, E>>> for ResultOption<T, E> {
+    /// Converts an `Option<Result<T, E>>`, as returned by `Iterator::next` on fallible
+    /// iterators, into a `ResultOption<T, E>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let mut lines = ["1", "x"].into_iter().map(str::parse::<u32>);
+    ///
+    /// let first: ResultOption<u32, _> = lines.next().into();
+    /// assert_eq!(first, ResultOption::Ok(1));
+    ///
+    /// let second: ResultOption<u32, _> = lines.next().into();
+    /// assert!(second.is_err());
+    ///
+    /// let end: ResultOption<u32, _> = lines.next().into();
+    /// assert!(end.is_none());
+    /// ```
+    fn from(o: Option<Result<T, E>>) -> Self {
+        match o {
+            Some(Ok(t)) => Self::Ok(t),
+            Some(Err(e)) => Self::Err(e),
+            None => Self::None,
+        }
+    }
+}
+
+impl<T, E> From<ResultOption<T, E>> for Option<Result<T, E>> {
+    /// Converts a `ResultOption<T, E>` into the nested `Option<Result<T, E>>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Ok(2);
+    /// assert_eq!(Option::from(x), Some(Ok(2)));
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::None;
+    /// assert_eq!(Option::<Result<u32, &str>>::from(x), None);
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Err("error");
+    /// assert_eq!(Option::from(x), Some(Err("error")));
+    /// ```
+    fn from(ro: ResultOption<T, E>) -> Self {
+        match ro {
+            ResultOption::Ok(t) => Some(Ok(t)),
+            ResultOption::None => None,
+            ResultOption::Err(e) => Some(Err(e)),
+        }
+    }
+}
+
 impl<T, E> From<Option<T>> for ResultOption<T, E> {
     /// Converts an `Option<T>` into a `ResultOption<T, E>` by taking ownership.
     ///
@@ -791,6 +1506,274 @@ impl<T: Clone, E> From<Option<&T>> for ResultOption<T, E> {
     }
 }
 
+/// `Display` implementation and custom rendering via `display_with`.
+mod display;
+pub use display::DisplayWith;
+
+/// Arithmetic operators and checked arithmetic.
+mod ops;
+pub use ops::CheckedOps;
+
+/// Kleene three-valued logic on `ResultOption<bool, E>`.
+mod logic;
+
+/// Alternative orderings via wrapper types.
+mod ordering;
+pub use ordering::{ErrFirst, NoneFirst, NoneLast, OkOnly};
+
+/// Iterator adapters over items of type `ResultOption`.
+mod iter;
+pub use iter::{AndThenOk, Errors, FilterOk, FlattenOk, MapOk, OkValues, ResultOptionIteratorExt};
+
+pub mod try_iter;
+pub use try_iter::TryIterator;
+
+/// Error accumulation instead of short-circuiting.
+mod accumulate;
+
+/// Outcomes carrying non-fatal warnings.
+mod diagnosed;
+pub use diagnosed::Diagnosed;
+
+/// Context messages on errors, in the spirit of `anyhow::Context`.
+mod context;
+pub use context::{ContextError, ResultOptionContext};
+
+/// Promoting `None` to an error that records the caller's location.
+mod require;
+pub use require::MissingError;
+
+/// Outcome statistics.
+mod stats;
+pub use stats::ResultOptionStats;
+
+/// Abstraction over `ResultOption` and equivalent nested std types.
+mod three_way;
+pub use three_way::ThreeWay;
+
+/// Assertion macros for tests.
+mod assertions;
+
+/// Pattern-matching macros with short variant names.
+mod matching;
+
+/// Derives conversions to and from `ResultOption<T, E>` for an enum with a value variant,
+/// an empty variant and an error variant, along with the common query and unwrap methods.
+///
+/// The variants are recognized by shape: the unit variant corresponds to `None`, and of
+/// the two variants with a single field, the first corresponds to `Ok` and the second to
+/// `Err`. Mark variants with `#[three_way(ok)]`, `#[three_way(none)]` or
+/// `#[three_way(err)]` to assign them explicitly.
+///
+/// The generated inherent methods delegate to `ResultOption`: `as_result_option`,
+/// `into_result_option`, `kind`, `is_ok`, `is_none`, `is_err`, `ok`, `err`, `unwrap_or`,
+/// `unwrap_or_else`, and the panicking `unwrap`, `expect`, `unwrap_err`, `expect_err` and
+/// `unwrap_option`. The panicking methods require the other payload to implement `Debug`;
+/// mark the enum `#[three_way(skip_unwrap)]` to leave them out.
+///
+/// The enum also implements the [`ThreeWay`](trait@ThreeWay) trait.
+///
+/// The generated code refers to this crate as `::result_option`. When it is renamed in
+/// `Cargo.toml` or reached through a re-export, set the path with
+/// `#[three_way(crate = path)]`.
+///
+/// # Examples
+///
+/// ```
+/// use result_option::{ResultOption, ThreeWay};
+///
+/// #[derive(ThreeWay, Debug, PartialEq)]
+/// enum Lookup {
+///     Found(u32),
+///     NotFound,
+///     Failed(String),
+/// }
+///
+/// let found = Lookup::Found(7);
+/// assert!(found.is_ok());
+/// assert_eq!(found.as_result_option(), ResultOption::Ok(&7));
+/// assert_eq!(found.unwrap(), 7);
+///
+/// let ro: ResultOption<u32, String> = Lookup::NotFound.into();
+/// assert_eq!(ro, ResultOption::None);
+/// assert_eq!(Lookup::from(ResultOption::Err("io".to_string())), Lookup::Failed("io".to_string()));
+///
+/// let nested: Result<Option<u32>, String> = Lookup::Found(7).convert();
+/// assert_eq!(nested, Ok(Some(7)));
+/// ```
+///
+/// Explicit roles and named fields:
+///
+/// ```
+/// use result_option::ThreeWay;
+///
+/// #[derive(ThreeWay)]
+/// #[three_way(skip_unwrap)]
+/// enum Fetch<T> {
+///     #[three_way(err)]
+///     Failed { reason: std::io::Error },
+///     #[three_way(none)]
+///     Missing,
+///     #[three_way(ok)]
+///     Fetched { body: T },
+/// }
+///
+/// let fetched = Fetch::Fetched { body: "hello" };
+/// assert!(fetched.is_ok());
+/// assert_eq!(fetched.ok(), Some("hello"));
+/// ```
+///
+/// Through a re-export:
+///
+/// ```
+/// mod deps {
+///     pub use result_option;
+/// }
+///
+/// use deps::result_option::ThreeWay;
+///
+/// #[derive(ThreeWay)]
+/// #[three_way(crate = deps::result_option)]
+/// enum Lookup {
+///     Found(u32),
+///     NotFound,
+///     Failed(String),
+/// }
+///
+/// assert!(Lookup::NotFound.is_none());
+/// assert_eq!(Lookup::Found(7).into_ro(), deps::result_option::ResultOption::Ok(7));
+/// ```
+#[cfg(feature = "derive")]
+pub use result_option_derive::ThreeWay;
+
+/// Lets a function returning `ResultOption<T, E>` use `?`, and generates a companion
+/// returning `Result<Option<T>, E>`.
+///
+/// Inside the function, `?` works on `Option<U>`, `Result<U, F>` and
+/// `ResultOption<U, F>` with `E: From<F>`: `None` returns `ResultOption::None`, and an
+/// error returns `ResultOption::Err` after conversion with `From`. `?` inside closures,
+/// async blocks and macro invocations keeps its usual meaning.
+///
+/// The companion `<name>_nested` takes the same arguments and returns the result
+/// converted into `Result<Option<T>, E>`, for callers that expect the nested form. It
+/// calls methods taking `self` as `self.<name>(..)`, and functions whose signature
+/// mentions `Self` as `Self::<name>(..)`. Mark other associated functions
+/// `#[result_option(associated)]`.
+///
+/// The rewritten `?` refers to this crate as `::result_option`. When it is renamed in
+/// `Cargo.toml` or reached through a re-export, set the path with
+/// `#[result_option(crate = path)]`, which combines with `associated` as
+/// `#[result_option(associated, crate = path)]`.
+///
+/// # Examples
+///
+/// ```
+/// use result_option::{result_option, ResultOption};
+/// use std::collections::HashMap;
+/// use std::num::ParseIntError;
+///
+/// #[result_option]
+/// fn port(config: &HashMap<&str, &str>, key: &str) -> ResultOption<u16, ParseIntError> {
+///     let value = config.get(key)?;
+///     let port: u16 = value.parse()?;
+///     ResultOption::Ok(port)
+/// }
+///
+/// let config = HashMap::from([("http", "8080"), ("ftp", "twenty-one")]);
+///
+/// assert_eq!(port(&config, "http"), ResultOption::Ok(8080));
+/// assert_eq!(port(&config, "ssh"), ResultOption::None);
+/// assert!(port(&config, "ftp").is_err());
+///
+/// assert_eq!(port_nested(&config, "http"), Ok(Some(8080)));
+/// assert_eq!(port_nested(&config, "ssh"), Ok(None));
+/// ```
+///
+/// Methods, and `?` on another `ResultOption`:
+///
+/// ```
+/// use result_option::{result_option, ResultOption};
+///
+/// struct Inventory(Vec<ResultOption<u32, String>>);
+///
+/// impl Inventory {
+///     #[result_option]
+///     fn total(&self, first: usize, second: usize) -> ResultOption<u32, String> {
+///         let a = self.0.get(first)?.clone()?;
+///         let b = self.0.get(second)?.clone()?;
+///         ResultOption::Ok(a + b)
+///     }
+/// }
+///
+/// let inventory = Inventory(vec![ResultOption::Ok(2), ResultOption::Ok(3), ResultOption::None]);
+/// assert_eq!(inventory.total(0, 1), ResultOption::Ok(5));
+/// assert_eq!(inventory.total(0, 2), ResultOption::None);
+/// assert_eq!(inventory.total_nested(0, 1), Ok(Some(5)));
+/// ```
+///
+/// Through a re-export:
+///
+/// ```
+/// mod deps {
+///     pub use result_option;
+/// }
+///
+/// use deps::result_option::{result_option, ResultOption};
+///
+/// struct Config;
+///
+/// impl Config {
+///     #[result_option(associated, crate = deps::result_option)]
+///     fn port(raw: Option<&str>) -> ResultOption<u16, std::num::ParseIntError> {
+///         ResultOption::Ok(raw?.parse()?)
+///     }
+/// }
+///
+/// assert_eq!(Config::port(Some("80")), ResultOption::Ok(80));
+/// assert_eq!(Config::port_nested(None), Ok(None));
+/// ```
+#[cfg(feature = "derive")]
+pub use result_option_derive::result_option;
+
+/// Support for `?` in functions marked `#[result_option]`.
+#[cfg(feature = "derive")]
+mod question;
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::question::Question;
+}
+
+/// Random generation for `proptest`, `quickcheck` and `arbitrary`.
+#[cfg(any(feature = "proptest", feature = "quickcheck", feature = "arbitrary"))]
+mod generate;
+#[cfg(any(feature = "proptest", feature = "quickcheck", feature = "arbitrary"))]
+pub use generate::VariantWeights;
+
+/// Law checks for combinators and conversions.
+#[cfg(feature = "testing")]
+pub mod testing;
+
+/// Outcome counters through `metrics`.
+#[cfg(feature = "metrics")]
+mod metrics_support;
+
+/// Logging of non-`Ok` outcomes through `log` and `tracing`.
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod logging;
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub use logging::OutcomeLevel;
+
+/// Conversions and context helpers for `anyhow::Error` and `eyre::Report`.
+#[cfg(any(feature = "anyhow", feature = "eyre"))]
+mod report_support;
+
+/// Parallel iteration support via `rayon`.
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rayon")]
+pub use parallel::ParallelResultOptionIteratorExt;
+
 /// Support for `UnwrapInfallible` trait when error type is `Infallible`.
 #[cfg(feature = "unwrap_infallible")]
 mod infallible;
@@ -0,0 +1,88 @@
+//! Logging of non-`Ok` outcomes through the `log` and `tracing` crates.
+//!
+//! Every event carries an `outcome` field (`"ok"`, `"none"` or `"err"`, see
+//! [`ResultOptionKind::as_str`]). Events about an `Err` also carry the error's
+//! `Display` output as `error` and its `Debug` output as `error_debug`.
+
+use crate::{ResultOption, ResultOptionKind};
+use core::fmt::{Arguments, Debug, Display};
+
+#[cfg(feature = "log")]
+mod log_backend;
+#[cfg(feature = "tracing")]
+mod tracing_backend;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A log level accepted by [`ResultOption::log_err`] and [`ResultOption::log_none`].
+///
+/// Implemented for `log::Level` under the `log` feature, and for `tracing::Level` under
+/// the `tracing` feature. The level type picks the crate the event is emitted through.
+pub trait OutcomeLevel: sealed::Sealed + Copy {
+    #[doc(hidden)]
+    fn emit(
+        self,
+        outcome: ResultOptionKind,
+        error: Option<(&dyn Display, &dyn Debug)>,
+        message: Arguments<'_>,
+    );
+}
+
+impl<T, E> ResultOption<T, E> {
+    /// Emits an event at `level` if the value is an `Err`, and returns `self` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// fn find(id: u32) -> ResultOption<String, std::io::Error> {
+    ///     ResultOption::Err(std::io::Error::other(format!("connection reset ({id})")))
+    /// }
+    ///
+    /// # #[cfg(feature = "log")]
+    /// let user = find(7).log_err(log::Level::Warn);
+    /// # #[cfg(all(feature = "tracing", not(feature = "log")))]
+    /// # let user = find(7).log_err(tracing::Level::WARN);
+    /// assert!(user.is_err());
+    /// ```
+    #[inline]
+    pub fn log_err<L: OutcomeLevel>(self, level: L) -> Self
+    where
+        E: Display + Debug,
+    {
+        if let Self::Err(e) = &self {
+            level.emit(
+                ResultOptionKind::Err,
+                Some((e, e)),
+                format_args!("`ResultOption` outcome is `Err`"),
+            );
+        }
+        self
+    }
+
+    /// Emits an event at `level` with the given message if the value is `None`, and
+    /// returns `self` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let user: ResultOption<String, std::io::Error> = ResultOption::None;
+    /// # #[cfg(feature = "log")]
+    /// let user = user.log_none(log::Level::Info, "user 7 not found");
+    /// # #[cfg(all(feature = "tracing", not(feature = "log")))]
+    /// # let user = user.log_none(tracing::Level::INFO, "user 7 not found");
+    /// assert!(user.is_none());
+    /// ```
+    #[inline]
+    pub fn log_none<L: OutcomeLevel, M: Display>(self, level: L, msg: M) -> Self {
+        if self.is_none() {
+            level.emit(ResultOptionKind::None, None, format_args!("{msg}"));
+        }
+        self
+    }
+}
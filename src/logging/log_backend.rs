@@ -0,0 +1,26 @@
+use super::{OutcomeLevel, sealed};
+use crate::ResultOptionKind;
+use core::fmt::{Arguments, Debug, Display};
+
+impl sealed::Sealed for log::Level {}
+
+impl OutcomeLevel for log::Level {
+    fn emit(
+        self,
+        outcome: ResultOptionKind,
+        error: Option<(&dyn Display, &dyn Debug)>,
+        message: Arguments<'_>,
+    ) {
+        let outcome = outcome.as_str();
+        match error {
+            Some((error, error_debug)) => log::log!(
+                self,
+                outcome = outcome,
+                error:% = error,
+                error_debug:? = error_debug;
+                "{message}"
+            ),
+            None => log::log!(self, outcome = outcome; "{message}"),
+        }
+    }
+}
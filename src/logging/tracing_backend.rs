@@ -0,0 +1,99 @@
+use super::{OutcomeLevel, sealed};
+use crate::{ResultOption, ResultOptionKind};
+use core::fmt::{Arguments, Debug, Display};
+use tracing::Level;
+use tracing::field::DebugValue;
+
+/// Emits a `tracing` event at a level only known at runtime.
+macro_rules! event_at {
+    ($level:expr, $($args:tt)+) => {
+        match $level {
+            Level::ERROR => tracing::event!(Level::ERROR, $($args)+),
+            Level::WARN => tracing::event!(Level::WARN, $($args)+),
+            Level::INFO => tracing::event!(Level::INFO, $($args)+),
+            Level::DEBUG => tracing::event!(Level::DEBUG, $($args)+),
+            Level::TRACE => tracing::event!(Level::TRACE, $($args)+),
+        }
+    };
+}
+
+impl sealed::Sealed for Level {}
+
+impl OutcomeLevel for Level {
+    fn emit(
+        self,
+        outcome: ResultOptionKind,
+        error: Option<(&dyn Display, &dyn Debug)>,
+        message: Arguments<'_>,
+    ) {
+        let outcome = outcome.as_str();
+        match error {
+            Some((error, error_debug)) => event_at!(
+                self,
+                outcome,
+                error = %error,
+                error_debug = ?error_debug,
+                "{message}"
+            ),
+            None => event_at!(self, outcome, "{message}"),
+        }
+    }
+}
+
+impl<T, E> ResultOption<T, E> {
+    /// Emits a `tracing` event describing the outcome, and returns `self` unchanged.
+    ///
+    /// The event carries a `name` field identifying the operation, besides the `outcome`
+    /// and error fields. `Ok` and `None` are reported at `DEBUG` level, `Err` at `ERROR`
+    /// level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let user: ResultOption<String, std::io::Error> = ResultOption::None;
+    /// let user = user.trace_outcome("users.find_by_id");
+    /// assert!(user.is_none());
+    /// ```
+    #[inline]
+    pub fn trace_outcome(self, name: &str) -> Self
+    where
+        E: Display + Debug,
+    {
+        let outcome = self.kind().as_str();
+        match &self {
+            Self::Ok(_) | Self::None => {
+                tracing::debug!(name, outcome, "`ResultOption` outcome");
+            }
+            Self::Err(e) => {
+                tracing::error!(name, outcome, error = %e, error_debug = ?e, "`ResultOption` outcome");
+            }
+        }
+        self
+    }
+
+    /// Returns a `tracing` field value recording the `Debug` output of `self`, such as
+    /// `Ok(42)`, `None` or `Err("timeout")`.
+    ///
+    /// `tracing::Value` is sealed, so it cannot be implemented for `ResultOption`
+    /// directly; use this adapter to record a `ResultOption` as a span or event field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let span = tracing::info_span!("lookup", result = tracing::field::Empty);
+    /// let found: ResultOption<u32, &str> = ResultOption::Ok(42);
+    /// span.record("result", found.as_value());
+    /// ```
+    #[inline]
+    pub fn as_value(&self) -> DebugValue<&Self>
+    where
+        T: Debug,
+        E: Debug,
+    {
+        tracing::field::debug(self)
+    }
+}
@@ -0,0 +1,169 @@
+//! Kleene three-valued logic on `ResultOption<bool, E>`.
+//!
+//! `Ok(true)` and `Ok(false)` are the known truth values and `None` means "unknown",
+//! following the semantics of SQL's `NULL` in boolean expressions. An `Err` on either
+//! side always propagates (the left one if both are `Err`), even when the other side
+//! would decide the outcome on its own.
+
+use crate::ResultOption;
+use core::ops::{BitAnd, BitOr, Not};
+
+impl<E> ResultOption<bool, E> {
+    /// Three-valued logical AND.
+    ///
+    /// `Ok(false)` on either side yields `Ok(false)`, even if the other side is unknown.
+    /// Otherwise, `None` on either side yields `None`. `Err` always propagates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let t: ResultOption<bool, &str> = ResultOption::Ok(true);
+    /// let f: ResultOption<bool, &str> = ResultOption::Ok(false);
+    /// let unknown: ResultOption<bool, &str> = ResultOption::None;
+    /// let err: ResultOption<bool, &str> = ResultOption::Err("failed to load");
+    ///
+    /// assert_eq!(t.and3(t), ResultOption::Ok(true));
+    /// assert_eq!(f.and3(unknown), ResultOption::Ok(false));
+    /// assert_eq!(t.and3(unknown), ResultOption::None);
+    /// assert_eq!(f.and3(err), ResultOption::Err("failed to load"));
+    /// ```
+    #[inline]
+    pub fn and3(self, rhs: Self) -> Self {
+        match (self, rhs) {
+            (Self::Err(e), _) | (_, Self::Err(e)) => Self::Err(e),
+            (Self::Ok(false), _) | (_, Self::Ok(false)) => Self::Ok(false),
+            (Self::None, _) | (_, Self::None) => Self::None,
+            (Self::Ok(true), Self::Ok(true)) => Self::Ok(true),
+        }
+    }
+
+    /// Three-valued logical OR.
+    ///
+    /// `Ok(true)` on either side yields `Ok(true)`, even if the other side is unknown.
+    /// Otherwise, `None` on either side yields `None`. `Err` always propagates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let t: ResultOption<bool, &str> = ResultOption::Ok(true);
+    /// let f: ResultOption<bool, &str> = ResultOption::Ok(false);
+    /// let unknown: ResultOption<bool, &str> = ResultOption::None;
+    /// let err: ResultOption<bool, &str> = ResultOption::Err("failed to load");
+    ///
+    /// assert_eq!(f.or3(f), ResultOption::Ok(false));
+    /// assert_eq!(t.or3(unknown), ResultOption::Ok(true));
+    /// assert_eq!(f.or3(unknown), ResultOption::None);
+    /// assert_eq!(t.or3(err), ResultOption::Err("failed to load"));
+    /// ```
+    #[inline]
+    pub fn or3(self, rhs: Self) -> Self {
+        match (self, rhs) {
+            (Self::Err(e), _) | (_, Self::Err(e)) => Self::Err(e),
+            (Self::Ok(true), _) | (_, Self::Ok(true)) => Self::Ok(true),
+            (Self::None, _) | (_, Self::None) => Self::None,
+            (Self::Ok(false), Self::Ok(false)) => Self::Ok(false),
+        }
+    }
+
+    /// Three-valued logical NOT.
+    ///
+    /// Known values are negated, while `None` and `Err` are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let t: ResultOption<bool, &str> = ResultOption::Ok(true);
+    /// assert_eq!(t.not3(), ResultOption::Ok(false));
+    ///
+    /// let unknown: ResultOption<bool, &str> = ResultOption::None;
+    /// assert_eq!(unknown.not3(), ResultOption::None);
+    /// ```
+    #[inline]
+    pub fn not3(self) -> Self {
+        self.map(|b| !b)
+    }
+
+    /// Three-valued material implication, equivalent to `self.not3().or3(rhs)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let t: ResultOption<bool, &str> = ResultOption::Ok(true);
+    /// let f: ResultOption<bool, &str> = ResultOption::Ok(false);
+    /// let unknown: ResultOption<bool, &str> = ResultOption::None;
+    ///
+    /// assert_eq!(t.implies3(f), ResultOption::Ok(false));
+    /// assert_eq!(f.implies3(unknown), ResultOption::Ok(true));
+    /// assert_eq!(unknown.implies3(t), ResultOption::Ok(true));
+    /// assert_eq!(t.implies3(unknown), ResultOption::None);
+    /// ```
+    #[inline]
+    pub fn implies3(self, rhs: Self) -> Self {
+        self.not3().or3(rhs)
+    }
+}
+
+impl<E> BitAnd for ResultOption<bool, E> {
+    type Output = Self;
+
+    /// Three-valued logical AND, same as [`ResultOption::and3`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let f: ResultOption<bool, &str> = ResultOption::Ok(false);
+    /// assert_eq!(f & ResultOption::None, ResultOption::Ok(false));
+    /// ```
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        self.and3(rhs)
+    }
+}
+
+impl<E> BitOr for ResultOption<bool, E> {
+    type Output = Self;
+
+    /// Three-valued logical OR, same as [`ResultOption::or3`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let t: ResultOption<bool, &str> = ResultOption::Ok(true);
+    /// assert_eq!(t | ResultOption::None, ResultOption::Ok(true));
+    /// ```
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        self.or3(rhs)
+    }
+}
+
+impl<E> Not for ResultOption<bool, E> {
+    type Output = Self;
+
+    /// Three-valued logical NOT, same as [`ResultOption::not3`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let t: ResultOption<bool, &str> = ResultOption::Ok(true);
+    /// assert_eq!(!t, ResultOption::Ok(false));
+    /// ```
+    #[inline]
+    fn not(self) -> Self {
+        self.not3()
+    }
+}
@@ -0,0 +1,128 @@
+/// Matches a `ResultOption` with short variant names.
+///
+/// Arms are written `ok(pattern)`, `none`, `err(pattern)` or `_`, each with an optional
+/// `if` guard, and expand to an ordinary `match` on the fully qualified variants. The
+/// compiler therefore checks the arms for exhaustiveness as usual.
+///
+/// # Examples
+///
+/// ```
+/// use result_option::{ro_match, ResultOption};
+///
+/// fn describe(x: ResultOption<u32, &str>) -> String {
+///     ro_match!(x,
+///         ok(0) => "zero".to_string(),
+///         ok(n) if n % 2 == 0 => format!("even {n}"),
+///         ok(n) => format!("odd {n}"),
+///         none => "nothing".to_string(),
+///         err(e) => {
+///             format!("failed: {e}")
+///         }
+///     )
+/// }
+///
+/// assert_eq!(describe(ResultOption::Ok(0)), "zero");
+/// assert_eq!(describe(ResultOption::Ok(4)), "even 4");
+/// assert_eq!(describe(ResultOption::Ok(5)), "odd 5");
+/// assert_eq!(describe(ResultOption::None), "nothing");
+/// assert_eq!(describe(ResultOption::Err("timeout")), "failed: timeout");
+/// ```
+///
+/// Missing variants are rejected at compile time:
+///
+/// ```compile_fail
+/// use result_option::{ro_match, ResultOption};
+///
+/// let x: ResultOption<u32, &str> = ResultOption::None;
+/// let n = ro_match!(x,
+///     ok(n) => n,
+///     err(_) => 0,
+/// );
+/// ```
+#[macro_export]
+macro_rules! ro_match {
+    (@arms ($ro:expr) { $($acc:tt)* }) => {
+        match $ro {
+            $($acc)*
+        }
+    };
+    (@arms ($ro:expr) { $($acc:tt)* } , $($rest:tt)*) => {
+        $crate::ro_match!(@arms ($ro) { $($acc)* } $($rest)*)
+    };
+    (@arms ($ro:expr) { $($acc:tt)* } ok $pat:tt $($rest:tt)*) => {
+        $crate::ro_match!(@arm ($ro) { $($acc)* } ($crate::ResultOption::Ok $pat) $($rest)*)
+    };
+    (@arms ($ro:expr) { $($acc:tt)* } none $($rest:tt)*) => {
+        $crate::ro_match!(@arm ($ro) { $($acc)* } ($crate::ResultOption::None) $($rest)*)
+    };
+    (@arms ($ro:expr) { $($acc:tt)* } err $pat:tt $($rest:tt)*) => {
+        $crate::ro_match!(@arm ($ro) { $($acc)* } ($crate::ResultOption::Err $pat) $($rest)*)
+    };
+    (@arms ($ro:expr) { $($acc:tt)* } _ $($rest:tt)*) => {
+        $crate::ro_match!(@arm ($ro) { $($acc)* } (_) $($rest)*)
+    };
+    (@arm ($ro:expr) { $($acc:tt)* } ($($pat:tt)*) $(if $guard:expr)? => $body:block $($rest:tt)*) => {
+        $crate::ro_match!(@arms ($ro) { $($acc)* $($pat)* $(if $guard)? => $body, } $($rest)*)
+    };
+    (@arm ($ro:expr) { $($acc:tt)* } ($($pat:tt)*) $(if $guard:expr)? => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::ro_match!(@arms ($ro) { $($acc)* $($pat)* $(if $guard)? => $body, } $($($rest)*)?)
+    };
+    ($ro:expr, $($arms:tt)*) => {
+        $crate::ro_match!(@arms ($ro) {} $($arms)*)
+    };
+}
+
+/// Binds the `Ok` value of a `ResultOption`, or runs a diverging block otherwise.
+///
+/// `let_ok!(pattern = expr else { ... })` expands to a `let`-`else` statement on
+/// `ResultOption::Ok(pattern)`. To inspect the error, write `else |e| { ... }`: `e` is
+/// an `Option<E>`, which is `None` when the value was `ResultOption::None`. As with
+/// `let`-`else`, the block must diverge.
+///
+/// # Examples
+///
+/// ```
+/// use result_option::{let_ok, ResultOption};
+///
+/// fn double(x: ResultOption<u32, &str>) -> Result<u32, String> {
+///     let_ok!(n = x else |e| {
+///         return Err(e.map_or("missing".to_string(), |e| format!("failed: {e}")));
+///     });
+///     Ok(n * 2)
+/// }
+///
+/// assert_eq!(double(ResultOption::Ok(21)), Ok(42));
+/// assert_eq!(double(ResultOption::None), Err("missing".to_string()));
+/// assert_eq!(double(ResultOption::Err("timeout")), Err("failed: timeout".to_string()));
+///
+/// fn first_even(xs: &[ResultOption<u32, &str>]) -> Option<u32> {
+///     for x in xs {
+///         let_ok!(&n = x.as_ref() else { continue });
+///         if n % 2 == 0 {
+///             return Some(n);
+///         }
+///     }
+///     None
+/// }
+///
+/// assert_eq!(first_even(&[ResultOption::None, ResultOption::Ok(3), ResultOption::Ok(4)]), Some(4));
+/// ```
+#[macro_export]
+macro_rules! let_ok {
+    (@split ($($pat:tt)*) ($($ro:tt)*) else | $err:pat_param | $diverge:block) => {
+        let ro = $($ro)*;
+        let $crate::ResultOption::Ok($($pat)*) = ro else {
+            let $err = $crate::ResultOption::err(ro);
+            $diverge
+        };
+    };
+    (@split ($($pat:tt)*) ($($ro:tt)*) else $diverge:block) => {
+        let $crate::ResultOption::Ok($($pat)*) = $($ro)* else $diverge;
+    };
+    (@split ($($pat:tt)*) ($($ro:tt)*) $next:tt $($rest:tt)*) => {
+        $crate::let_ok!(@split ($($pat)*) ($($ro)* $next) $($rest)*)
+    };
+    ($pat:pat = $($rest:tt)*) => {
+        $crate::let_ok!(@split ($pat) () $($rest)*)
+    };
+}
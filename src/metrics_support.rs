@@ -0,0 +1,24 @@
+use crate::ResultOption;
+
+impl<T, E> ResultOption<T, E> {
+    /// Increments the `metrics` counter `name`, labelled with the outcome, and returns
+    /// `self` unchanged.
+    ///
+    /// The `outcome` label is `"ok"`, `"none"` or `"err"`, see
+    /// [`ResultOptionKind::as_str`](crate::ResultOptionKind::as_str).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let cached: ResultOption<u32, &str> = ResultOption::None;
+    /// let cached = cached.record_outcome("cache_lookups_total");
+    /// assert!(cached.is_none());
+    /// ```
+    #[inline]
+    pub fn record_outcome(self, name: &'static str) -> Self {
+        metrics::counter!(name, "outcome" => self.kind().as_str()).increment(1);
+        self
+    }
+}
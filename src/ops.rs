@@ -0,0 +1,283 @@
+//! Arithmetic operators on `ResultOption` values.
+//!
+//! Binary operators combine two `ResultOption`s and apply the operator to the `Ok` values.
+//! When either side is not `Ok`, the outcome follows this precedence:
+//!
+//! 1. `Err` dominates everything; if both sides are `Err`, the left one is kept.
+//! 2. Otherwise, `None` on either side yields `None`.
+//! 3. Otherwise both sides are `Ok` and the operator is applied.
+//!
+//! The compound assignment operators (`+=`, `-=`, ...) follow the same rules.
+//!
+//! The `checked_*` methods do the same for integers, but turn overflow and division
+//! by zero into `None` (or into a caller-supplied `Err` for the `checked_*_or` variants).
+
+use crate::ResultOption;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+impl<T, E> ResultOption<T, E> {
+    /// Combines two `ResultOption`s, applying `f` to the `Ok` values.
+    ///
+    /// `Err` dominates `None`, and the left `Err` is kept if both are `Err`.
+    fn zip_with<U, V, F: FnOnce(T, U) -> V>(
+        self,
+        rhs: ResultOption<U, E>,
+        f: F,
+    ) -> ResultOption<V, E> {
+        match (self, rhs) {
+            (Self::Err(e), _) | (_, ResultOption::Err(e)) => ResultOption::Err(e),
+            (Self::None, _) | (_, ResultOption::None) => ResultOption::None,
+            (Self::Ok(a), ResultOption::Ok(b)) => ResultOption::Ok(f(a, b)),
+        }
+    }
+
+    /// In-place counterpart of `zip_with`, used by the compound assignment operators.
+    fn zip_assign_with<U, F: FnOnce(&mut T, U)>(&mut self, rhs: ResultOption<U, E>, f: F) {
+        match (&mut *self, rhs) {
+            (Self::Err(_), _) => {}
+            (_, ResultOption::Err(e)) => *self = Self::Err(e),
+            (Self::None, _) => {}
+            (_, ResultOption::None) => *self = Self::None,
+            (Self::Ok(a), ResultOption::Ok(b)) => f(a, b),
+        }
+    }
+}
+
+macro_rules! impl_binary_op {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, $sym:tt, $ok:literal) => {
+        impl<T: $Op<U>, U, E> $Op<ResultOption<U, E>> for ResultOption<T, E> {
+            type Output = ResultOption<T::Output, E>;
+
+            #[doc = concat!("Applies `", stringify!($sym), "` to the `Ok` values.")]
+            ///
+            /// If either side is `Err`, the result is that `Err` (the left one if both are).
+            /// Otherwise, if either side is `None`, the result is `None`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use result_option::ResultOption;
+            ///
+            /// let six: ResultOption<i32, &str> = ResultOption::Ok(6);
+            /// let three: ResultOption<i32, &str> = ResultOption::Ok(3);
+            /// let none: ResultOption<i32, &str> = ResultOption::None;
+            /// let err: ResultOption<i32, &str> = ResultOption::Err("error");
+            ///
+            #[doc = concat!("assert_eq!(six ", stringify!($sym), " three, ResultOption::Ok(", $ok, "));")]
+            #[doc = concat!("assert_eq!(six ", stringify!($sym), " none, ResultOption::None);")]
+            #[doc = concat!("assert_eq!(none ", stringify!($sym), " err, ResultOption::Err(\"error\"));")]
+            /// ```
+            #[inline]
+            fn $op(self, rhs: ResultOption<U, E>) -> Self::Output {
+                self.zip_with(rhs, T::$op)
+            }
+        }
+
+        impl<T: $OpAssign<U>, U, E> $OpAssign<ResultOption<U, E>> for ResultOption<T, E> {
+            #[doc = concat!("Applies `", stringify!($sym), "=` to the `Ok` value in place.")]
+            ///
+            /// If either side is `Err`, `self` becomes that `Err` (it is kept if both are).
+            /// Otherwise, if either side is `None`, `self` becomes `None`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use result_option::ResultOption;
+            ///
+            /// let mut x: ResultOption<i32, &str> = ResultOption::Ok(6);
+            #[doc = concat!("x ", stringify!($sym), "= ResultOption::Ok(3);")]
+            #[doc = concat!("assert_eq!(x, ResultOption::Ok(", $ok, "));")]
+            ///
+            #[doc = concat!("x ", stringify!($sym), "= ResultOption::<i32, &str>::None;")]
+            /// assert_eq!(x, ResultOption::None);
+            /// ```
+            #[inline]
+            fn $op_assign(&mut self, rhs: ResultOption<U, E>) {
+                self.zip_assign_with(rhs, T::$op_assign);
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, AddAssign, add_assign, +, "9");
+impl_binary_op!(Sub, sub, SubAssign, sub_assign, -, "3");
+impl_binary_op!(Mul, mul, MulAssign, mul_assign, *, "18");
+impl_binary_op!(Div, div, DivAssign, div_assign, /, "2");
+impl_binary_op!(Rem, rem, RemAssign, rem_assign, %, "0");
+
+impl<T: Neg, E> Neg for ResultOption<T, E> {
+    type Output = ResultOption<T::Output, E>;
+
+    /// Negates the `Ok` value, leaving `None` and `Err` unchanged.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.map(T::neg)
+    }
+}
+
+/// Integer types supporting checked arithmetic.
+///
+/// This trait backs the `checked_*` methods of [`ResultOption`], such as
+/// [`ResultOption::checked_add`]. It is implemented for all primitive integer types.
+pub trait CheckedOps: Sized {
+    /// Checked addition, returning `None` on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// Checked subtraction, returning `None` on overflow.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// Checked multiplication, returning `None` on overflow.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// Checked division, returning `None` on overflow or division by zero.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    /// Checked remainder, returning `None` on overflow or division by zero.
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    /// Checked negation, returning `None` on overflow.
+    fn checked_neg(self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_ops {
+    ($($t:ty)*) => {$(
+        impl CheckedOps for $t {
+            #[inline]
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+            #[inline]
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+            #[inline]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+            #[inline]
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_div(self, rhs)
+            }
+            #[inline]
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_rem(self, rhs)
+            }
+            #[inline]
+            fn checked_neg(self) -> Option<Self> {
+                <$t>::checked_neg(self)
+            }
+        }
+    )*};
+}
+
+impl_checked_ops!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+macro_rules! checked_binary_methods {
+    ($($op:ident, $op_or:ident, $sym:literal, $failure:literal, $ok_lhs:literal, $ok_rhs:literal, $ok:literal, $bad_lhs:literal, $bad_rhs:literal;)*) => {$(
+        #[doc = concat!("Checked `", $sym, "` of the `Ok` values, returning `None` on ", $failure, ".")]
+        ///
+        /// If either side is `Err`, the result is that `Err` (the left one if both are).
+        /// Otherwise, if either side is `None`, the result is `None`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use result_option::ResultOption;
+        ///
+        #[doc = concat!("let x: ResultOption<u8, &str> = ResultOption::Ok(", $ok_lhs, ");")]
+        #[doc = concat!("assert_eq!(x.", stringify!($op), "(ResultOption::Ok(", $ok_rhs, ")), ResultOption::Ok(", $ok, "));")]
+        ///
+        #[doc = concat!("let x: ResultOption<u8, &str> = ResultOption::Ok(", $bad_lhs, ");")]
+        #[doc = concat!("assert_eq!(x.", stringify!($op), "(ResultOption::Ok(", $bad_rhs, ")), ResultOption::None);")]
+        /// ```
+        #[inline]
+        pub fn $op(self, rhs: Self) -> Self {
+            match self.zip_with(rhs, T::$op) {
+                ResultOption::Ok(Some(v)) => ResultOption::Ok(v),
+                ResultOption::Ok(None) | ResultOption::None => ResultOption::None,
+                ResultOption::Err(e) => ResultOption::Err(e),
+            }
+        }
+
+        #[doc = concat!("Checked `", $sym, "` of the `Ok` values, returning `Err(err)` on ", $failure, ".")]
+        ///
+        /// If either side is `Err`, the result is that `Err` (the left one if both are).
+        /// Otherwise, if either side is `None`, the result is `None`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use result_option::ResultOption;
+        ///
+        #[doc = concat!("let x: ResultOption<u8, &str> = ResultOption::Ok(", $ok_lhs, ");")]
+        #[doc = concat!("assert_eq!(x.", stringify!($op_or), "(ResultOption::Ok(", $ok_rhs, "), \"", $failure, "\"), ResultOption::Ok(", $ok, "));")]
+        ///
+        #[doc = concat!("let x: ResultOption<u8, &str> = ResultOption::Ok(", $bad_lhs, ");")]
+        #[doc = concat!("assert_eq!(x.", stringify!($op_or), "(ResultOption::Ok(", $bad_rhs, "), \"", $failure, "\"), ResultOption::Err(\"", $failure, "\"));")]
+        /// ```
+        #[inline]
+        pub fn $op_or(self, rhs: Self, err: E) -> Self {
+            match self.zip_with(rhs, T::$op) {
+                ResultOption::Ok(Some(v)) => ResultOption::Ok(v),
+                ResultOption::Ok(None) => ResultOption::Err(err),
+                ResultOption::None => ResultOption::None,
+                ResultOption::Err(e) => ResultOption::Err(e),
+            }
+        }
+    )*};
+}
+
+impl<T: CheckedOps, E> ResultOption<T, E> {
+    checked_binary_methods! {
+        checked_add, checked_add_or, "+", "overflow", "2", "3", "5", "200", "100";
+        checked_sub, checked_sub_or, "-", "overflow", "5", "3", "2", "3", "5";
+        checked_mul, checked_mul_or, "*", "overflow", "2", "3", "6", "100", "3";
+        checked_div, checked_div_or, "/", "division by zero", "6", "3", "2", "6", "0";
+        checked_rem, checked_rem_or, "%", "division by zero", "7", "3", "1", "7", "0";
+    }
+
+    /// Checked negation of the `Ok` value, returning `None` on overflow.
+    ///
+    /// `None` and `Err` are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<i8, &str> = ResultOption::Ok(5);
+    /// assert_eq!(x.checked_neg(), ResultOption::Ok(-5));
+    ///
+    /// let x: ResultOption<i8, &str> = ResultOption::Ok(i8::MIN);
+    /// assert_eq!(x.checked_neg(), ResultOption::None);
+    /// ```
+    #[inline]
+    pub fn checked_neg(self) -> Self {
+        match self {
+            Self::Ok(t) => t.checked_neg().into(),
+            Self::None => Self::None,
+            Self::Err(e) => Self::Err(e),
+        }
+    }
+
+    /// Checked negation of the `Ok` value, returning `Err(err)` on overflow.
+    ///
+    /// `None` and `Err` are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<i8, &str> = ResultOption::Ok(5);
+    /// assert_eq!(x.checked_neg_or("overflow"), ResultOption::Ok(-5));
+    ///
+    /// let x: ResultOption<i8, &str> = ResultOption::Ok(i8::MIN);
+    /// assert_eq!(x.checked_neg_or("overflow"), ResultOption::Err("overflow"));
+    /// ```
+    #[inline]
+    pub fn checked_neg_or(self, err: E) -> Self {
+        match self {
+            Self::Ok(t) => t.checked_neg().map_or(Self::Err(err), Self::Ok),
+            Self::None => Self::None,
+            Self::Err(e) => Self::Err(e),
+        }
+    }
+}
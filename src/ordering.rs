@@ -0,0 +1,292 @@
+//! Alternative orderings for `ResultOption`.
+//!
+//! The derived `Ord` of [`ResultOption`] sorts by declaration order, `Ok < None < Err`.
+//! The wrappers in this module order the variants differently, while values of the same
+//! variant still compare by their payload.
+
+use crate::{ResultOption, ResultOptionKind};
+use core::cmp::Ordering;
+
+fn partial_cmp_ranked<T: PartialOrd, E: PartialOrd>(
+    a: &ResultOption<T, E>,
+    b: &ResultOption<T, E>,
+    rank: fn(ResultOptionKind) -> u8,
+) -> Option<Ordering> {
+    match (a, b) {
+        (ResultOption::Ok(x), ResultOption::Ok(y)) => x.partial_cmp(y),
+        (ResultOption::Err(x), ResultOption::Err(y)) => x.partial_cmp(y),
+        _ => rank(a.kind()).partial_cmp(&rank(b.kind())),
+    }
+}
+
+fn cmp_ranked<T: Ord, E: Ord>(
+    a: &ResultOption<T, E>,
+    b: &ResultOption<T, E>,
+    rank: fn(ResultOptionKind) -> u8,
+) -> Ordering {
+    match (a, b) {
+        (ResultOption::Ok(x), ResultOption::Ok(y)) => x.cmp(y),
+        (ResultOption::Err(x), ResultOption::Err(y)) => x.cmp(y),
+        _ => rank(a.kind()).cmp(&rank(b.kind())),
+    }
+}
+
+macro_rules! ranked_wrapper {
+    (
+        $(#[$meta:meta])*
+        $name:ident, order: $first:ident < $second:ident < $third:ident
+    ) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+        pub struct $name<T, E>(pub ResultOption<T, E>);
+
+        impl<T, E> $name<T, E> {
+            const fn rank(kind: ResultOptionKind) -> u8 {
+                match kind {
+                    ResultOptionKind::$first => 0,
+                    ResultOptionKind::$second => 1,
+                    ResultOptionKind::$third => 2,
+                }
+            }
+
+            #[doc = concat!("Builds an owned `", stringify!($name), "` sort key from a borrowed `ResultOption`.")]
+            ///
+            /// Meant to be passed directly to [`slice::sort_by_key`], which calls it on every
+            /// comparison and so clones the value each time. For payloads that are costly to
+            #[doc = concat!("clone, sort with [`", stringify!($name), "::compare`] or [`slice::sort_by_cached_key`] instead.")]
+            #[inline]
+            pub fn key(ro: &ResultOption<T, E>) -> Self
+            where
+                T: Clone,
+                E: Clone,
+            {
+                Self(ro.clone())
+            }
+
+            #[doc = concat!("Compares two borrowed `ResultOption`s in the `", stringify!($name), "` order, without cloning.")]
+            ///
+            /// Meant to be passed directly to [`slice::sort_by`].
+            #[inline]
+            pub fn compare(a: &ResultOption<T, E>, b: &ResultOption<T, E>) -> Ordering
+            where
+                T: Ord,
+                E: Ord,
+            {
+                cmp_ranked(a, b, Self::rank)
+            }
+        }
+
+        impl<T: PartialOrd, E: PartialOrd> PartialOrd for $name<T, E> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                partial_cmp_ranked(&self.0, &other.0, Self::rank)
+            }
+        }
+
+        impl<T: Ord, E: Ord> Ord for $name<T, E> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                cmp_ranked(&self.0, &other.0, Self::rank)
+            }
+        }
+    };
+}
+
+ranked_wrapper! {
+    /// Orders `ResultOption`s as `None < Ok < Err`, putting missing values first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{NoneFirst, ResultOption};
+    ///
+    /// let mut rows: Vec<ResultOption<u32, &str>> = vec![
+    ///     ResultOption::Err("timeout"),
+    ///     ResultOption::Ok(2),
+    ///     ResultOption::None,
+    ///     ResultOption::Ok(1),
+    /// ];
+    /// rows.sort_by_key(NoneFirst::key);
+    ///
+    /// assert_eq!(
+    ///     rows,
+    ///     [ResultOption::None, ResultOption::Ok(1), ResultOption::Ok(2), ResultOption::Err("timeout")]
+    /// );
+    ///
+    /// // Comparing borrowed values avoids cloning the payloads on every comparison
+    /// let mut names: Vec<ResultOption<String, String>> = vec![
+    ///     ResultOption::Ok("bob".to_string()),
+    ///     ResultOption::None,
+    ///     ResultOption::Ok("alice".to_string()),
+    /// ];
+    /// names.sort_by(NoneFirst::compare);
+    ///
+    /// assert_eq!(
+    ///     names,
+    ///     [ResultOption::None, ResultOption::Ok("alice".to_string()), ResultOption::Ok("bob".to_string())]
+    /// );
+    /// ```
+    NoneFirst, order: None < Ok < Err
+}
+
+ranked_wrapper! {
+    /// Orders `ResultOption`s as `Ok < Err < None`, putting missing values last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{NoneLast, ResultOption};
+    ///
+    /// let mut rows: Vec<ResultOption<u32, &str>> = vec![
+    ///     ResultOption::None,
+    ///     ResultOption::Err("timeout"),
+    ///     ResultOption::Ok(2),
+    ///     ResultOption::Ok(1),
+    /// ];
+    /// rows.sort_by_key(NoneLast::key);
+    ///
+    /// assert_eq!(
+    ///     rows,
+    ///     [ResultOption::Ok(1), ResultOption::Ok(2), ResultOption::Err("timeout"), ResultOption::None]
+    /// );
+    /// ```
+    NoneLast, order: Ok < Err < None
+}
+
+ranked_wrapper! {
+    /// Orders `ResultOption`s as `Err < Ok < None`, putting errors first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ErrFirst, ResultOption};
+    ///
+    /// let mut rows: Vec<ResultOption<u32, &str>> = vec![
+    ///     ResultOption::None,
+    ///     ResultOption::Ok(1),
+    ///     ResultOption::Err("timeout"),
+    /// ];
+    /// rows.sort_by_key(ErrFirst::key);
+    ///
+    /// assert_eq!(rows, [ResultOption::Err("timeout"), ResultOption::Ok(1), ResultOption::None]);
+    ///
+    /// // Wrapping references works too, without cloning
+    /// let worst = rows.iter().map(|r| ErrFirst(r.as_ref())).min().unwrap();
+    /// assert_eq!(worst.0, ResultOption::Err(&"timeout"));
+    /// ```
+    ErrFirst, order: Err < Ok < None
+}
+
+/// Compares only the `Ok` values of `ResultOption`s.
+///
+/// `Ok` values are ordered by their payload and come before all other values.
+/// `None` and `Err` values are all equal to each other, whatever their payload.
+///
+/// # Examples
+///
+/// ```
+/// use result_option::{OkOnly, ResultOption};
+///
+/// let mut rows: Vec<ResultOption<u32, &str>> = vec![
+///     ResultOption::Err("timeout"),
+///     ResultOption::Ok(2),
+///     ResultOption::None,
+///     ResultOption::Ok(1),
+/// ];
+/// // The sort is stable, so `Err` and `None` keep their relative order
+/// rows.sort_by_key(OkOnly::key);
+///
+/// assert_eq!(
+///     rows,
+///     [ResultOption::Ok(1), ResultOption::Ok(2), ResultOption::Err("timeout"), ResultOption::None]
+/// );
+///
+/// assert_eq!(OkOnly(ResultOption::<u32, &str>::None), OkOnly(ResultOption::Err("timeout")));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct OkOnly<T, E>(pub ResultOption<T, E>);
+
+impl<T, E> OkOnly<T, E> {
+    /// Builds an owned `OkOnly` sort key from a borrowed `ResultOption`.
+    ///
+    /// Meant to be passed directly to [`slice::sort_by_key`]. Only the `Ok` value is
+    /// cloned, so `E` does not need to implement `Clone`. The key is rebuilt on every
+    /// comparison, so for payloads that are costly to clone, sort with
+    /// [`OkOnly::compare`] or [`slice::sort_by_cached_key`] instead.
+    #[inline]
+    pub fn key(ro: &ResultOption<T, E>) -> Self
+    where
+        T: Clone,
+    {
+        Self(match ro {
+            ResultOption::Ok(t) => ResultOption::Ok(t.clone()),
+            ResultOption::None | ResultOption::Err(_) => ResultOption::None,
+        })
+    }
+
+    /// Compares two borrowed `ResultOption`s in the `OkOnly` order, without cloning.
+    ///
+    /// Meant to be passed directly to [`slice::sort_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{OkOnly, ResultOption};
+    ///
+    /// let mut rows: Vec<ResultOption<String, &str>> = vec![
+    ///     ResultOption::None,
+    ///     ResultOption::Ok("b".to_string()),
+    ///     ResultOption::Ok("a".to_string()),
+    /// ];
+    /// rows.sort_by(OkOnly::compare);
+    ///
+    /// assert_eq!(
+    ///     rows,
+    ///     [ResultOption::Ok("a".to_string()), ResultOption::Ok("b".to_string()), ResultOption::None]
+    /// );
+    /// ```
+    #[inline]
+    pub fn compare(a: &ResultOption<T, E>, b: &ResultOption<T, E>) -> Ordering
+    where
+        T: Ord,
+    {
+        OkOnly(a.as_ref()).cmp(&OkOnly(b.as_ref()))
+    }
+}
+
+impl<T: PartialEq, E> PartialEq for OkOnly<T, E> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (ResultOption::Ok(x), ResultOption::Ok(y)) => x == y,
+            (ResultOption::Ok(_), _) | (_, ResultOption::Ok(_)) => false,
+            _ => true,
+        }
+    }
+}
+
+impl<T: Eq, E> Eq for OkOnly<T, E> {}
+
+impl<T: PartialOrd, E> PartialOrd for OkOnly<T, E> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (&self.0, &other.0) {
+            (ResultOption::Ok(x), ResultOption::Ok(y)) => x.partial_cmp(y),
+            (ResultOption::Ok(_), _) => Some(Ordering::Less),
+            (_, ResultOption::Ok(_)) => Some(Ordering::Greater),
+            _ => Some(Ordering::Equal),
+        }
+    }
+}
+
+impl<T: Ord, E> Ord for OkOnly<T, E> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.0, &other.0) {
+            (ResultOption::Ok(x), ResultOption::Ok(y)) => x.cmp(y),
+            (ResultOption::Ok(_), _) => Ordering::Less,
+            (_, ResultOption::Ok(_)) => Ordering::Greater,
+            _ => Ordering::Equal,
+        }
+    }
+}
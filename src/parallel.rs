@@ -0,0 +1,164 @@
+use crate::ResultOption;
+use rayon::iter::{FromParallelIterator, IntoParallelIterator, ParallelIterator};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+impl<C, T, E> FromParallelIterator<ResultOption<T, E>> for ResultOption<C, E>
+where
+    C: FromParallelIterator<T>,
+    T: Send,
+    E: Send,
+{
+    /// Collects the `Ok` values into a collection, in parallel.
+    ///
+    /// Matches the sequential `FromIterator` implementation: returns an `Err` if there
+    /// is any, otherwise `None` if any item is `None`, otherwise `Ok` with the collection.
+    /// If several items are `Err`, which one is returned is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use result_option::ResultOption;
+    ///
+    /// let all: ResultOption<Vec<u32>, &str> =
+    ///     (1..=3).into_par_iter().map(ResultOption::Ok).collect();
+    /// assert_eq!(all, ResultOption::Ok(vec![1, 2, 3]));
+    ///
+    /// let all: ResultOption<Vec<u32>, &str> = (0..100)
+    ///     .into_par_iter()
+    ///     .map(|i| if i == 50 { ResultOption::None } else { ResultOption::Ok(i) })
+    ///     .collect();
+    /// assert_eq!(all, ResultOption::None);
+    /// ```
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = ResultOption<T, E>>,
+    {
+        let saved_error = Mutex::new(None);
+        let none_seen = AtomicBool::new(false);
+        let collection = par_iter
+            .into_par_iter()
+            .filter_map(|item| match item {
+                ResultOption::Ok(t) => Some(Some(t)),
+                ResultOption::None => {
+                    none_seen.store(true, Ordering::Relaxed);
+                    None
+                }
+                ResultOption::Err(e) => {
+                    if let Ok(mut guard) = saved_error.lock() {
+                        guard.get_or_insert(e);
+                    }
+                    Some(None)
+                }
+            })
+            .while_some()
+            .collect();
+
+        match saved_error.into_inner().ok().flatten() {
+            Some(e) => ResultOption::Err(e),
+            None if none_seen.into_inner() => ResultOption::None,
+            None => ResultOption::Ok(collection),
+        }
+    }
+}
+
+impl<T: Send, E> IntoParallelIterator for ResultOption<T, E> {
+    type Item = T;
+    type Iter = rayon::option::IntoIter<T>;
+
+    /// Returns a parallel iterator over the `Ok` value, yielding nothing for `None` and `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use result_option::ResultOption;
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Ok(2);
+    /// assert_eq!(x.into_par_iter().collect::<Vec<_>>(), [2]);
+    ///
+    /// let x: ResultOption<u32, &str> = ResultOption::Err("error");
+    /// assert_eq!(x.into_par_iter().count(), 0);
+    /// ```
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.ok().into_par_iter()
+    }
+}
+
+impl<'a, T: Sync, E> IntoParallelIterator for &'a ResultOption<T, E> {
+    type Item = &'a T;
+    type Iter = rayon::option::IntoIter<&'a T>;
+
+    /// Returns a parallel iterator over a reference to the `Ok` value, yielding nothing
+    /// for `None` and `Err`.
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_ref().ok().into_par_iter()
+    }
+}
+
+/// Extension methods for parallel iterators yielding [`ResultOption`] items.
+///
+/// Implemented for every `ParallelIterator<Item = ResultOption<T, E>>`.
+pub trait ParallelResultOptionIteratorExt<T, E>:
+    ParallelIterator<Item = ResultOption<T, E>>
+{
+    /// Splits the items into `Ok` values, a count of `None`s and `Err` values, in parallel.
+    ///
+    /// This is the parallel counterpart of
+    /// [`partition_ro`](crate::ResultOptionIteratorExt::partition_ro). For indexed
+    /// iterators, such as those over a `Vec` or a range, the values keep their
+    /// original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use result_option::{ParallelResultOptionIteratorExt, ResultOption};
+    ///
+    /// let (oks, nones, errs) = (0..10u32)
+    ///     .into_par_iter()
+    ///     .map(|i| match i % 3 {
+    ///         0 => ResultOption::Ok(i),
+    ///         1 => ResultOption::None,
+    ///         _ => ResultOption::Err(i),
+    ///     })
+    ///     .par_partition_ro();
+    ///
+    /// assert_eq!(oks, [0, 3, 6, 9]);
+    /// assert_eq!(nones, 3);
+    /// assert_eq!(errs, [2, 5, 8]);
+    /// ```
+    fn par_partition_ro(self) -> (Vec<T>, usize, Vec<E>)
+    where
+        T: Send,
+        E: Send,
+    {
+        self.fold(
+            || (Vec::new(), 0, Vec::new()),
+            |(mut oks, mut nones, mut errs), item| {
+                match item {
+                    ResultOption::Ok(t) => oks.push(t),
+                    ResultOption::None => nones += 1,
+                    ResultOption::Err(e) => errs.push(e),
+                }
+                (oks, nones, errs)
+            },
+        )
+        .reduce(
+            || (Vec::new(), 0, Vec::new()),
+            |(mut oks, nones, mut errs), (mut more_oks, more_nones, mut more_errs)| {
+                oks.append(&mut more_oks);
+                errs.append(&mut more_errs);
+                (oks, nones + more_nones, errs)
+            },
+        )
+    }
+}
+
+impl<I, T, E> ParallelResultOptionIteratorExt<T, E> for I where
+    I: ParallelIterator<Item = ResultOption<T, E>>
+{
+}
@@ -0,0 +1,42 @@
+use crate::ResultOption;
+
+/// Splits a value into the payload to continue with, or the `ResultOption` to return early.
+///
+/// This is what `?` expands to inside functions marked `#[result_option]`.
+pub trait Question<E> {
+    /// The value `?` evaluates to.
+    type Ok;
+
+    /// Returns `Ok` with the value to continue with, or the `None` or `Err` to return.
+    fn question(self) -> ResultOption<Self::Ok, E>;
+}
+
+impl<T, E> Question<E> for Option<T> {
+    type Ok = T;
+
+    #[inline]
+    fn question(self) -> ResultOption<T, E> {
+        self.into()
+    }
+}
+
+impl<T, F, E: From<F>> Question<E> for Result<T, F> {
+    type Ok = T;
+
+    #[inline]
+    fn question(self) -> ResultOption<T, E> {
+        match self {
+            Ok(t) => ResultOption::Ok(t),
+            Err(e) => ResultOption::Err(e.into()),
+        }
+    }
+}
+
+impl<T, F, E: From<F>> Question<E> for ResultOption<T, F> {
+    type Ok = T;
+
+    #[inline]
+    fn question(self) -> ResultOption<T, E> {
+        self.map_err(Into::into)
+    }
+}
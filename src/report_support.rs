@@ -0,0 +1,181 @@
+use crate::{ContextError, ResultOption};
+use core::fmt::{Debug, Display};
+use std::error::Error;
+
+/// Generates the support for one report type, since `anyhow` and `eyre` have the same shape.
+///
+/// The context helpers are inherent methods on `ResultOption<T, Report>` rather than
+/// implementations of `ResultOptionContext`: the report types may implement
+/// `std::error::Error` in the future, which would make such an implementation overlap with
+/// the blanket one for `E: Error`.
+///
+/// - `krate`: the crate, whose `Result` alias is used
+/// - `report`: the report type, with `msg` and `new` constructors
+/// - `report_name`: the report type as a string, for docs
+/// - `into_report` and `into_report_result`: names of the conversion methods
+/// - `wrap`: the report's method attaching a context message
+/// - `report_macro`: the crate's macro building a report from a message, for docs
+macro_rules! report_support {
+    (
+        krate: $krate:ident,
+        report: $report:ty,
+        report_name: $report_name:literal,
+        into_report: $into_report:ident,
+        into_report_result: $into_report_result:ident,
+        wrap: $wrap:ident,
+        report_macro: $report_macro:literal $(,)?
+    ) => {
+        impl<T, E: Into<$report>> ResultOption<T, E> {
+            #[doc = concat!("Converts into a `", stringify!($krate), "::Result<Option<T>>`, converting the error into `", $report_name, "`.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use result_option::ResultOption;
+            ///
+            #[doc = concat!("fn find(id: u32) -> ", stringify!($krate), "::Result<Option<String>> {")]
+            ///     let found: ResultOption<String, std::io::Error> = ResultOption::None;
+            #[doc = concat!("    found.", stringify!($into_report), "()")]
+            /// }
+            ///
+            /// assert!(find(1).unwrap().is_none());
+            /// ```
+            #[inline]
+            pub fn $into_report(self) -> $krate::Result<Option<T>> {
+                match self {
+                    Self::Ok(t) => Ok(Some(t)),
+                    Self::None => Ok(None),
+                    Self::Err(e) => Err(e.into()),
+                }
+            }
+
+            #[doc = concat!("Converts into a `", stringify!($krate), "::Result<T>`, turning `None` into an error with `none_msg`.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use result_option::ResultOption;
+            ///
+            #[doc = concat!("let found: ResultOption<u32, ", $report_name, "> = ResultOption::None;")]
+            #[doc = concat!("let err = found.", stringify!($into_report_result), "(\"user not found\").unwrap_err();")]
+            /// assert_eq!(err.to_string(), "user not found");
+            ///
+            /// let found: ResultOption<u32, std::io::Error> = ResultOption::Ok(7);
+            #[doc = concat!("assert_eq!(found.", stringify!($into_report_result), "(\"user not found\").unwrap(), 7);")]
+            /// ```
+            #[inline]
+            pub fn $into_report_result<C>(self, none_msg: C) -> $krate::Result<T>
+            where
+                C: Display + Debug + Send + Sync + 'static,
+            {
+                match self {
+                    Self::Ok(t) => Ok(t),
+                    Self::None => Err(<$report>::msg(none_msg)),
+                    Self::Err(e) => Err(e.into()),
+                }
+            }
+        }
+
+        #[doc = concat!("The [`ResultOptionContext`](crate::ResultOptionContext) helpers for `", $report_name, "`.")]
+        ///
+        /// These are inherent methods with the same names as the trait's, so they do not
+        /// satisfy `ResultOptionContext` bounds in generic code.
+        #[doc = concat!("Messages are attached with `", $report_name, "::", stringify!($wrap), "`.")]
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use result_option::ResultOption;
+        ///
+        #[doc = concat!("let found: ResultOption<u32, ", $report_name, "> = ResultOption::Err(", $report_macro, "(\"timeout\"));")]
+        /// let err = found.context("failed to load user").unwrap_err();
+        /// assert_eq!(format!("{err:#}"), "failed to load user: timeout");
+        /// ```
+        impl<T> ResultOption<T, $report> {
+            /// Wraps the `Err` value with a context message. `Ok` and `None` are left unchanged.
+            #[inline]
+            pub fn context<C: Display>(self, context: C) -> Self {
+                self.map_err(|e| e.$wrap(context.to_string()))
+            }
+
+            /// Wraps the `Err` value with a lazily computed context message. `Ok` and `None`
+            /// are left unchanged.
+            #[inline]
+            pub fn with_context<C: Display, F: FnOnce() -> C>(self, f: F) -> Self {
+                self.map_err(|e| e.$wrap(f().to_string()))
+            }
+
+            /// Turns `None` into an `Err` carrying the context message, for when absence is
+            /// unexpected. An existing `Err` is passed through without the message.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use result_option::ResultOption;
+            ///
+            #[doc = concat!("let found: ResultOption<u32, ", $report_name, "> = ResultOption::None;")]
+            /// let err = found.context_none("user 3 must exist").unwrap_err();
+            /// assert_eq!(err.to_string(), "user 3 must exist");
+            /// ```
+            #[inline]
+            pub fn context_none<C: Display>(self, context: C) -> $krate::Result<T> {
+                match self {
+                    Self::Ok(t) => Ok(t),
+                    Self::None => Err(<$report>::msg(context.to_string())),
+                    Self::Err(e) => Err(e),
+                }
+            }
+        }
+
+        impl<E: Error + Send + Sync + 'static> From<ContextError<E>> for $report {
+            #[doc = concat!("Converts into an `", $report_name, "`, attaching each message as context.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use result_option::{ResultOption, ResultOptionContext};
+            ///
+            #[doc = concat!("fn load(raw: &str) -> ", stringify!($krate), "::Result<u16> {")]
+            ///     let port: ResultOption<u16, _> = raw.parse::<u16>().map(Some).into();
+            ///     Ok(port.context("invalid port").context_none("port missing")?)
+            /// }
+            ///
+            /// let err = load("http").unwrap_err();
+            /// assert_eq!(format!("{err:#}"), "invalid port: invalid digit found in string");
+            /// ```
+            fn from(error: ContextError<E>) -> Self {
+                let mut messages = error.messages.into_iter();
+                let mut converted = match error.source {
+                    Some(source) => <$report>::new(source),
+                    None => <$report>::msg(messages.next().unwrap_or_default()),
+                };
+                for message in messages {
+                    converted = converted.$wrap(message);
+                }
+                converted
+            }
+        }
+    };
+}
+
+#[cfg(feature = "anyhow")]
+report_support! {
+    krate: anyhow,
+    report: anyhow::Error,
+    report_name: "anyhow::Error",
+    into_report: into_anyhow,
+    into_report_result: into_anyhow_result,
+    wrap: context,
+    report_macro: "anyhow::anyhow!",
+}
+
+#[cfg(feature = "eyre")]
+report_support! {
+    krate: eyre,
+    report: eyre::Report,
+    report_name: "eyre::Report",
+    into_report: into_eyre,
+    into_report_result: into_eyre_result,
+    wrap: wrap_err,
+    report_macro: "eyre::eyre!",
+}
@@ -0,0 +1,151 @@
+use crate::ResultOption;
+use core::fmt::{self, Display, Formatter};
+use core::panic::Location;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::error::Error;
+
+/// The error produced when [`ResultOption::require`] or [`ResultOption::require_with`]
+/// finds a `None`.
+///
+/// It records the source location of the call and, when enabled through the
+/// `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables, a backtrace.
+#[derive(Debug)]
+pub struct MissingError {
+    message: Option<String>,
+    location: &'static Location<'static>,
+    backtrace: Backtrace,
+}
+
+impl MissingError {
+    /// Creates a `MissingError` recording the location of the caller.
+    #[track_caller]
+    #[inline]
+    pub fn new(message: Option<String>) -> Self {
+        Self {
+            message,
+            location: Location::caller(),
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Returns the message given to [`ResultOption::require_with`], if any.
+    #[must_use]
+    #[inline]
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Returns the source location where the `None` was promoted to an error.
+    #[must_use]
+    #[inline]
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// Returns the backtrace captured when the `None` was promoted to an error.
+    ///
+    /// Its [`status`](Backtrace::status) tells whether it was actually captured.
+    #[inline]
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+}
+
+impl Display for MissingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.message {
+            Some(message) => write!(f, "{message} at {}", self.location)?,
+            None => write!(f, "required value was `None` at {}", self.location)?,
+        }
+        if f.alternate() && self.backtrace.status() == BacktraceStatus::Captured {
+            write!(f, "\n\nStack backtrace:\n{}", self.backtrace)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for MissingError {}
+
+impl<T, E> ResultOption<T, E> {
+    /// Returns the `Ok` value, turning `None` into a [`MissingError`] that records the
+    /// caller's location. An `Err` is passed through unchanged.
+    ///
+    /// This gives the diagnostics of [`unwrap`](ResultOption::unwrap) without panicking.
+    /// The error type only needs to be constructible from a `MissingError`, which is the
+    /// case for `Box<dyn Error>` and `anyhow::Error`, among others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ResultOption;
+    /// use std::error::Error;
+    ///
+    /// fn find(id: u32) -> ResultOption<&'static str, Box<dyn Error>> {
+    ///     if id == 1 { ResultOption::Ok("alice") } else { ResultOption::None }
+    /// }
+    ///
+    /// assert_eq!(find(1).require().unwrap(), "alice");
+    ///
+    /// let line = line!() + 1;
+    /// let err = find(2).require().unwrap_err();
+    /// let message = err.to_string();
+    /// assert!(message.starts_with(&format!("required value was `None` at {}:{line}:", file!())));
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn require(self) -> Result<T, E>
+    where
+        E: From<MissingError>,
+    {
+        match self {
+            Self::Ok(t) => Ok(t),
+            Self::None => Err(MissingError::new(None).into()),
+            Self::Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the `Ok` value, turning `None` into a [`MissingError`] with the given
+    /// message that records the caller's location. An `Err` is passed through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{MissingError, ResultOption};
+    ///
+    /// #[derive(Debug)]
+    /// enum LookupError {
+    ///     Missing(MissingError),
+    ///     Timeout,
+    /// }
+    ///
+    /// impl From<MissingError> for LookupError {
+    ///     fn from(e: MissingError) -> Self {
+    ///         LookupError::Missing(e)
+    ///     }
+    /// }
+    ///
+    /// let found: ResultOption<u32, LookupError> = ResultOption::None;
+    /// match found.require_with("user 7 must exist") {
+    ///     Err(LookupError::Missing(e)) => {
+    ///         assert_eq!(e.message(), Some("user 7 must exist"));
+    ///         assert_eq!(e.location().file(), file!());
+    ///     }
+    ///     other => panic!("unexpected {other:?}"),
+    /// }
+    ///
+    /// let found: ResultOption<u32, LookupError> = ResultOption::Err(LookupError::Timeout);
+    /// assert!(matches!(found.require_with("user 7 must exist"), Err(LookupError::Timeout)));
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn require_with<M: Display>(self, msg: M) -> Result<T, E>
+    where
+        E: From<MissingError>,
+    {
+        match self {
+            Self::Ok(t) => Ok(t),
+            Self::None => Err(MissingError::new(Some(msg.to_string())).into()),
+            Self::Err(e) => Err(e),
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{ResultOption, ResultOptionKind};
+use std::collections::BTreeMap;
+
+/// Counts of `Ok`, `None` and `Err` outcomes, such as cache hits, misses and errors.
+///
+/// Outcomes are added with [`record`](ResultOptionStats::record), through the [`Extend`]
+/// implementation, or by [tapping](ResultOptionStats::tap) an iterator as it is consumed.
+/// Errors can also be broken down by a key computed from each error, using the
+/// `*_keyed` methods.
+///
+/// # Examples
+///
+/// ```
+/// use result_option::{ResultOption, ResultOptionKind, ResultOptionStats};
+///
+/// let lookups: [ResultOption<u32, &str>; 4] = [
+///     ResultOption::Ok(1),
+///     ResultOption::None,
+///     ResultOption::Ok(2),
+///     ResultOption::Err("timeout"),
+/// ];
+///
+/// let mut stats = ResultOptionStats::new();
+/// stats.extend(lookups);
+///
+/// assert_eq!(stats.count(ResultOptionKind::Ok), 2);
+/// assert_eq!(stats.total(), 4);
+/// assert_eq!(stats.ratio(ResultOptionKind::Ok), Some(0.5));
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct ResultOptionStats<K = ()> {
+    ok: u64,
+    none: u64,
+    err: u64,
+    errors_by_key: BTreeMap<K, u64>,
+}
+
+impl ResultOptionStats {
+    /// Creates empty statistics without an error breakdown.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::keyed()
+    }
+}
+
+impl<K> ResultOptionStats<K> {
+    /// Creates empty statistics with errors broken down by keys of type `K`.
+    #[inline]
+    pub const fn keyed() -> Self {
+        Self {
+            ok: 0,
+            none: 0,
+            err: 0,
+            errors_by_key: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the number of outcomes of the given kind.
+    #[must_use]
+    #[inline]
+    pub const fn count(&self, kind: ResultOptionKind) -> u64 {
+        match kind {
+            ResultOptionKind::Ok => self.ok,
+            ResultOptionKind::None => self.none,
+            ResultOptionKind::Err => self.err,
+        }
+    }
+
+    /// Returns the total number of outcomes.
+    #[must_use]
+    #[inline]
+    pub const fn total(&self) -> u64 {
+        self.ok + self.none + self.err
+    }
+
+    /// Returns the share of outcomes of the given kind, or `None` if nothing was recorded.
+    #[must_use]
+    #[inline]
+    pub fn ratio(&self, kind: ResultOptionKind) -> Option<f64> {
+        match self.total() {
+            0 => None,
+            total => Some(self.count(kind) as f64 / total as f64),
+        }
+    }
+
+    /// Returns the number of errors per key, as recorded by the `*_keyed` methods.
+    #[inline]
+    pub const fn errors_by_key(&self) -> &BTreeMap<K, u64> {
+        &self.errors_by_key
+    }
+
+    /// Records one outcome, without adding it to the error breakdown.
+    #[inline]
+    pub fn record<T, E>(&mut self, ro: &ResultOption<T, E>) {
+        match ro.kind() {
+            ResultOptionKind::Ok => self.ok += 1,
+            ResultOptionKind::None => self.none += 1,
+            ResultOptionKind::Err => self.err += 1,
+        }
+    }
+
+    /// Records one outcome, adding an `Err` to the error breakdown under the key
+    /// computed by `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ResultOption, ResultOptionStats};
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// let mut stats = ResultOptionStats::keyed();
+    /// for lookup in [
+    ///     ResultOption::<u32, Error>::Err(ErrorKind::TimedOut.into()),
+    ///     ResultOption::Err(ErrorKind::TimedOut.into()),
+    ///     ResultOption::Err(ErrorKind::PermissionDenied.into()),
+    ///     ResultOption::Ok(1),
+    /// ] {
+    ///     stats.record_keyed(&lookup, |e| e.kind().to_string());
+    /// }
+    ///
+    /// assert_eq!(stats.errors_by_key()["timed out"], 2);
+    /// assert_eq!(stats.errors_by_key()["permission denied"], 1);
+    /// ```
+    #[inline]
+    pub fn record_keyed<T, E, F>(&mut self, ro: &ResultOption<T, E>, key: F)
+    where
+        K: Ord,
+        F: FnOnce(&E) -> K,
+    {
+        self.record(ro);
+        if let ResultOption::Err(e) = ro {
+            *self.errors_by_key.entry(key(e)).or_insert(0) += 1;
+        }
+    }
+
+    /// Records every item of `iter` as it is consumed, passing the items through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::{ResultOption, ResultOptionKind, ResultOptionStats};
+    ///
+    /// let lookups = [ResultOption::Ok(1), ResultOption::None, ResultOption::Err("timeout")];
+    ///
+    /// let mut stats = ResultOptionStats::new();
+    /// let found: Vec<u32> = stats.tap(lookups).filter_map(ResultOption::ok).collect();
+    ///
+    /// assert_eq!(found, [1]);
+    /// assert_eq!(stats.count(ResultOptionKind::None), 1);
+    /// assert_eq!(stats.count(ResultOptionKind::Err), 1);
+    /// ```
+    #[inline]
+    pub fn tap<I, T, E>(&mut self, iter: I) -> impl Iterator<Item = ResultOption<T, E>>
+    where
+        I: IntoIterator<Item = ResultOption<T, E>>,
+    {
+        iter.into_iter().inspect(move |ro| self.record(ro))
+    }
+
+    /// Records every item of `iter` as it is consumed, like [`tap`](ResultOptionStats::tap),
+    /// also adding errors to the breakdown under the key computed by `key`.
+    #[inline]
+    pub fn tap_keyed<I, T, E, F>(
+        &mut self,
+        iter: I,
+        mut key: F,
+    ) -> impl Iterator<Item = ResultOption<T, E>>
+    where
+        I: IntoIterator<Item = ResultOption<T, E>>,
+        K: Ord,
+        F: FnMut(&E) -> K,
+    {
+        iter.into_iter()
+            .inspect(move |ro| self.record_keyed(ro, &mut key))
+    }
+
+    /// Adds the counts of `other` to `self`, for example to combine per-thread statistics.
+    pub fn merge(&mut self, other: Self)
+    where
+        K: Ord,
+    {
+        self.ok += other.ok;
+        self.none += other.none;
+        self.err += other.err;
+        for (key, count) in other.errors_by_key {
+            *self.errors_by_key.entry(key).or_insert(0) += count;
+        }
+    }
+}
+
+impl<K> Default for ResultOptionStats<K> {
+    #[inline]
+    fn default() -> Self {
+        Self::keyed()
+    }
+}
+
+impl<K, T, E> Extend<ResultOption<T, E>> for ResultOptionStats<K> {
+    /// Records every item, without adding errors to the breakdown.
+    #[inline]
+    fn extend<I: IntoIterator<Item = ResultOption<T, E>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|ro| self.record(&ro));
+    }
+}
+
+impl<'a, K, T, E> Extend<&'a ResultOption<T, E>> for ResultOptionStats<K> {
+    /// Records every item, without adding errors to the breakdown.
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a ResultOption<T, E>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|ro| self.record(ro));
+    }
+}
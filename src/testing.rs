@@ -0,0 +1,156 @@
+//! Law checks for `ResultOption` combinators and conversions.
+//!
+//! Each function checks a law for every sample it is given and panics with the offending
+//! sample if the law does not hold. Samples can come from any generator, e.g. a fixed
+//! list, `std::iter::repeat_with(generate).take(n)`, or a property-testing framework
+//! calling the check once per generated value.
+//!
+//! The conversion checks take the target type as a parameter, so the same laws can be
+//! checked for your own `From` impls between domain types and `ResultOption`.
+//!
+//! # Examples
+//!
+//! ```
+//! use result_option::testing;
+//! use result_option::ResultOption;
+//!
+//! let samples = [ResultOption::Ok(2), ResultOption::None, ResultOption::Err("boom")];
+//!
+//! testing::check_functor_laws(samples, |x: u32| x + 1, |x: u32| x * 10);
+//! testing::check_monad_laws(
+//!     samples,
+//!     |x: u32| ResultOption::from_bool(x > 1, || x - 1),
+//!     |x: u32| x.checked_mul(2).map_or(ResultOption::Err("overflow"), ResultOption::Ok),
+//! );
+//! testing::check_round_trip::<_, _, Result<Option<u32>, &str>>(samples);
+//! testing::check_round_trip::<_, _, Option<Result<u32, &str>>>(samples);
+//! testing::check_round_trip_from::<u32, &str, _>([Ok(Some(2)), Ok(None), Err("boom")]);
+//! ```
+
+use crate::ResultOption;
+use std::fmt::Debug;
+
+/// Checks the functor laws for [`map`](ResultOption::map) on every sample:
+///
+/// - identity: `ro.map(|x| x) == ro`
+/// - composition: `ro.map(f).map(g) == ro.map(|x| g(f(x)))`
+///
+/// # Panics
+///
+/// Panics with the sample and both sides of the equation if a law does not hold.
+#[track_caller]
+pub fn check_functor_laws<T, E, U, V, F, G, I>(samples: I, f: F, g: G)
+where
+    T: Clone + PartialEq + Debug,
+    E: Clone + PartialEq + Debug,
+    V: PartialEq + Debug,
+    F: Fn(T) -> U,
+    G: Fn(U) -> V,
+    I: IntoIterator<Item = ResultOption<T, E>>,
+{
+    for ro in samples {
+        let identity = ro.clone().map(|x| x);
+        assert_law("functor identity", &ro, &identity, &ro);
+
+        let chained = ro.clone().map(&f).map(&g);
+        let composed = ro.clone().map(|x| g(f(x)));
+        assert_law("functor composition", &ro, &chained, &composed);
+    }
+}
+
+/// Checks the monad laws for [`and_then`](ResultOption::and_then) on every sample:
+///
+/// - left identity: `ResultOption::Ok(a).and_then(f) == f(a)`, for every `Ok(a)` sample
+/// - right identity: `ro.and_then(ResultOption::Ok) == ro`
+/// - associativity: `ro.and_then(f).and_then(g) == ro.and_then(|x| f(x).and_then(g))`
+///
+/// # Panics
+///
+/// Panics with the sample and both sides of the equation if a law does not hold.
+#[track_caller]
+pub fn check_monad_laws<T, E, U, V, F, G, I>(samples: I, f: F, g: G)
+where
+    T: Clone + PartialEq + Debug,
+    E: Clone + PartialEq + Debug,
+    U: PartialEq + Debug,
+    V: PartialEq + Debug,
+    F: Fn(T) -> ResultOption<U, E>,
+    G: Fn(U) -> ResultOption<V, E>,
+    I: IntoIterator<Item = ResultOption<T, E>>,
+{
+    for ro in samples {
+        if let ResultOption::Ok(a) = &ro {
+            let bound = ResultOption::Ok(a.clone()).and_then(&f);
+            assert_law("monad left identity", &ro, &bound, &f(a.clone()));
+        }
+
+        let right = ro.clone().and_then(ResultOption::Ok);
+        assert_law("monad right identity", &ro, &right, &ro);
+
+        let chained = ro.clone().and_then(&f).and_then(&g);
+        let nested = ro.clone().and_then(|x| f(x).and_then(&g));
+        assert_law("monad associativity", &ro, &chained, &nested);
+    }
+}
+
+/// Checks that converting every sample into `X` and back yields the sample unchanged.
+///
+/// `X` is typically `Result<Option<T>, E>`, `Option<Result<T, E>>`, or a domain type
+/// with its own `From` impls.
+///
+/// # Panics
+///
+/// Panics with the sample, its converted form and the result of converting back if
+/// the round trip changes the value.
+#[track_caller]
+pub fn check_round_trip<T, E, X>(samples: impl IntoIterator<Item = ResultOption<T, E>>)
+where
+    T: Clone + PartialEq + Debug,
+    E: Clone + PartialEq + Debug,
+    X: From<ResultOption<T, E>> + Into<ResultOption<T, E>> + Debug,
+{
+    for ro in samples {
+        let converted = X::from(ro.clone());
+        let repr = format!("{converted:?}");
+        let back: ResultOption<T, E> = converted.into();
+        assert!(
+            back == ro,
+            "round trip through `{}` changed the value\n  sample: {ro:?}\n    into: {repr}\n    back: {back:?}",
+            std::any::type_name::<X>(),
+        );
+    }
+}
+
+/// Checks that converting every sample of `X` into a `ResultOption` and back yields the
+/// sample unchanged, the converse of [`check_round_trip`].
+///
+/// # Panics
+///
+/// Panics with the sample, its converted form and the result of converting back if
+/// the round trip changes the value.
+#[track_caller]
+pub fn check_round_trip_from<T, E, X>(samples: impl IntoIterator<Item = X>)
+where
+    T: Debug,
+    E: Debug,
+    X: Clone + PartialEq + Debug + From<ResultOption<T, E>> + Into<ResultOption<T, E>>,
+{
+    for x in samples {
+        let converted: ResultOption<T, E> = x.clone().into();
+        let repr = format!("{converted:?}");
+        let back = X::from(converted);
+        assert!(
+            back == x,
+            "round trip from `{}` changed the value\n  sample: {x:?}\n    into: {repr}\n    back: {back:?}",
+            std::any::type_name::<X>(),
+        );
+    }
+}
+
+#[track_caller]
+fn assert_law<S: Debug, V: PartialEq + Debug>(law: &str, sample: &S, left: &V, right: &V) {
+    assert!(
+        left == right,
+        "{law} law violated\n  sample: {sample:?}\n    left: {left:?}\n   right: {right:?}",
+    );
+}
@@ -0,0 +1,143 @@
+use crate::{ResultOption, ResultOptionKind};
+
+/// A type that represents a value, its absence or an error, like `ResultOption`.
+///
+/// `ThreeWay` converts losslessly to and from `ResultOption<Self::Ok, Self::Err>`, so
+/// generic code can accept any of these representations and work on the `ResultOption`
+/// form. It is implemented for `ResultOption<T, E>`, `Result<Option<T>, E>` and
+/// `Option<Result<T, E>>`, and can be implemented for domain types, for example with
+/// `#[derive(ThreeWay)]` behind the `derive` feature.
+///
+/// # Examples
+///
+/// ```
+/// use result_option::{ResultOption, ResultOptionKind, ThreeWay};
+///
+/// fn double<R: ThreeWay<Ok = u32>>(r: R) -> R {
+///     R::from_ro(r.into_ro().map(|n| n * 2))
+/// }
+///
+/// assert_eq!(double(ResultOption::<_, &str>::Ok(2)), ResultOption::Ok(4));
+/// assert_eq!(double(Ok::<_, &str>(Some(2))), Ok(Some(4)));
+/// assert_eq!(double(Some(Err::<u32, _>("boom"))), Some(Err("boom")));
+///
+/// assert_eq!(Ok::<Option<u32>, &str>(None).kind(), ResultOptionKind::None);
+/// ```
+///
+/// `ThreeWay` is not implemented for `Poll<Option<Result<T, E>>>`, the result of polling a
+/// stream, because `Poll::Pending` is none of the three outcomes. Convert the ready value
+/// with `poll.map(ThreeWay::into_ro)` into a `Poll<ResultOption<T, E>>` instead, so
+/// `Pending` is handled explicitly rather than counted as an outcome:
+///
+/// ```
+/// use result_option::{ResultOption, ThreeWay};
+/// use std::task::Poll;
+///
+/// let polled: Poll<Option<Result<u32, &str>>> = Poll::Ready(Some(Ok(3)));
+/// assert_eq!(polled.map(ThreeWay::into_ro), Poll::Ready(ResultOption::Ok(3)));
+///
+/// let pending: Poll<Option<Result<u32, &str>>> = Poll::Pending;
+/// assert_eq!(pending.map(ThreeWay::into_ro), Poll::Pending);
+/// ```
+pub trait ThreeWay: Sized {
+    /// The type of the value in the `Ok` case.
+    type Ok;
+    /// The type of the error in the `Err` case.
+    type Err;
+
+    /// Converts `self` into a `ResultOption`.
+    fn into_ro(self) -> ResultOption<Self::Ok, Self::Err>;
+
+    /// Converts a `ResultOption` into `Self`.
+    fn from_ro(ro: ResultOption<Self::Ok, Self::Err>) -> Self;
+
+    /// Returns which of the three cases `self` represents.
+    fn kind(&self) -> ResultOptionKind;
+
+    /// Converts `self` into another representation of the same outcome.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::ThreeWay;
+    ///
+    /// let nested: Result<Option<u32>, &str> = Ok(None);
+    /// let flipped: Option<Result<u32, &str>> = nested.convert();
+    /// assert_eq!(flipped, None);
+    /// ```
+    #[inline]
+    fn convert<U>(self) -> U
+    where
+        U: ThreeWay<Ok = Self::Ok, Err = Self::Err>,
+    {
+        U::from_ro(self.into_ro())
+    }
+}
+
+impl<T, E> ThreeWay for ResultOption<T, E> {
+    type Ok = T;
+    type Err = E;
+
+    #[inline]
+    fn into_ro(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn from_ro(ro: Self) -> Self {
+        ro
+    }
+
+    #[inline]
+    fn kind(&self) -> ResultOptionKind {
+        ResultOption::kind(self)
+    }
+}
+
+impl<T, E> ThreeWay for Result<Option<T>, E> {
+    type Ok = T;
+    type Err = E;
+
+    #[inline]
+    fn into_ro(self) -> ResultOption<T, E> {
+        self.into()
+    }
+
+    #[inline]
+    fn from_ro(ro: ResultOption<T, E>) -> Self {
+        ro.into()
+    }
+
+    #[inline]
+    fn kind(&self) -> ResultOptionKind {
+        match self {
+            Ok(Some(_)) => ResultOptionKind::Ok,
+            Ok(None) => ResultOptionKind::None,
+            Err(_) => ResultOptionKind::Err,
+        }
+    }
+}
+
+impl<T, E> ThreeWay for Option<Result<T, E>> {
+    type Ok = T;
+    type Err = E;
+
+    #[inline]
+    fn into_ro(self) -> ResultOption<T, E> {
+        self.into()
+    }
+
+    #[inline]
+    fn from_ro(ro: ResultOption<T, E>) -> Self {
+        ro.into()
+    }
+
+    #[inline]
+    fn kind(&self) -> ResultOptionKind {
+        match self {
+            Some(Ok(_)) => ResultOptionKind::Ok,
+            None => ResultOptionKind::None,
+            Some(Err(_)) => ResultOptionKind::Err,
+        }
+    }
+}
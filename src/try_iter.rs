@@ -0,0 +1,511 @@
+//! Iteration that can fail, where `next` returns a [`ResultOption`].
+//!
+//! A [`TryIterator`] reports each step as `Ok(item)`, `None` at the end of the stream,
+//! or `Err(error)` on failure. This is the natural shape of database cursors and
+//! file readers, where running out of items and failing to read the next one are
+//! different outcomes.
+//!
+//! Use [`convert`] to adapt an `Iterator<Item = Result<T, E>>`, and
+//! [`TryIterator::iterator`] to go back.
+
+use crate::ResultOption;
+
+#[cfg(feature = "fallible_iterator")]
+mod fallible;
+#[cfg(feature = "fallible_iterator")]
+pub use fallible::{FromFallible, IntoFallible};
+
+/// An iterator whose `next` can fail.
+///
+/// # Examples
+///
+/// ```
+/// use result_option::ResultOption;
+/// use result_option::try_iter::TryIterator;
+///
+/// /// Reads numbers from lines, failing on the first unparsable one.
+/// struct Numbers<'a> {
+///     lines: std::str::Lines<'a>,
+/// }
+///
+/// impl TryIterator for Numbers<'_> {
+///     type Item = u32;
+///     type Error = std::num::ParseIntError;
+///
+///     fn next(&mut self) -> ResultOption<u32, Self::Error> {
+///         match self.lines.next() {
+///             Some(line) => line.parse().map(Some).into(),
+///             None => ResultOption::None,
+///         }
+///     }
+/// }
+///
+/// let numbers = Numbers { lines: "1\n2\n3\n4".lines() };
+/// let even: Vec<u32> = numbers.filter(|n| n % 2 == 0).map(|n| n * 10).collect().unwrap();
+/// assert_eq!(even, [20, 40]);
+///
+/// let numbers = Numbers { lines: "1\nx\n3".lines() };
+/// assert!(numbers.collect::<Vec<_>>().is_err());
+/// ```
+pub trait TryIterator {
+    /// The type of the elements being iterated over.
+    type Item;
+
+    /// The type of the error that can occur while iterating.
+    type Error;
+
+    /// Advances the iterator and returns the next value.
+    ///
+    /// Returns `None` when iteration is finished, and `Err` if the next value could not
+    /// be produced.
+    fn next(&mut self) -> ResultOption<Self::Item, Self::Error>;
+
+    /// Returns the bounds on the remaining length of the iterator.
+    ///
+    /// The default implementation returns `(0, None)`, which is correct for any iterator.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+
+    /// Borrows the iterator, so that adapters can be applied without consuming it.
+    #[inline]
+    fn by_ref(&mut self) -> &mut Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
+    /// Maps every item with `f`.
+    #[inline]
+    fn map<B, F>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> B,
+    {
+        Map { iter: self, f }
+    }
+
+    /// Maps every error with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::try_iter::{self, TryIterator};
+    ///
+    /// let items = [Ok(1), Err("broken")];
+    /// let result: Result<Vec<u32>, String> =
+    ///     try_iter::convert(items).map_err(|e| format!("read failed: {e}")).collect();
+    /// assert_eq!(result, Err("read failed: broken".to_string()));
+    /// ```
+    #[inline]
+    fn map_err<B, F>(self, f: F) -> MapErr<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Error) -> B,
+    {
+        MapErr { iter: self, f }
+    }
+
+    /// Yields only the items matching `predicate`. Errors are always passed through.
+    #[inline]
+    fn filter<P>(self, predicate: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        Filter {
+            iter: self,
+            predicate,
+        }
+    }
+
+    /// Yields at most `n` items. Errors are passed through and do not count as items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::try_iter::{self, TryIterator};
+    ///
+    /// let items = [Ok(1), Err("skipped"), Ok(2), Ok(3)];
+    /// let mut first_two = try_iter::convert(items).take(2);
+    /// assert!(first_two.next().is_ok());
+    /// assert!(first_two.next().is_err());
+    /// assert!(first_two.next().is_ok());
+    /// assert!(first_two.next().is_none());
+    /// ```
+    ///
+    /// Since errors do not count, the upper bound of the size hint is not capped at `n`:
+    ///
+    /// ```
+    /// use result_option::try_iter::{self, TryIterator};
+    ///
+    /// let items = try_iter::convert([Err("a"), Err("b"), Ok(1)]).take(1).iterator();
+    /// assert_eq!(items.size_hint(), (1, Some(3)));
+    /// assert_eq!(items.count(), 3);
+    /// ```
+    #[inline]
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take { iter: self, n }
+    }
+
+    /// Yields the items of `self`, then the items of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::try_iter::{self, TryIterator};
+    ///
+    /// let first = try_iter::convert([Ok::<_, &str>(1), Ok(2)]);
+    /// let second = try_iter::convert([Ok(3)]);
+    /// assert_eq!(first.chain(second).collect::<Vec<_>>(), Ok(vec![1, 2, 3]));
+    /// ```
+    #[inline]
+    fn chain<I>(self, other: I) -> Chain<Self, I>
+    where
+        Self: Sized,
+        I: TryIterator<Item = Self::Item, Error = Self::Error>,
+    {
+        Chain {
+            first: Some(self),
+            second: other,
+        }
+    }
+
+    /// Folds every item into an accumulator, stopping at the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::try_iter::{self, TryIterator};
+    ///
+    /// let sum = try_iter::convert([Ok::<_, &str>(1), Ok(2), Ok(3)]).fold(0, |acc, n| acc + n);
+    /// assert_eq!(sum, Ok(6));
+    /// ```
+    #[inline]
+    fn fold<B, F>(mut self, init: B, mut f: F) -> Result<B, Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        loop {
+            match self.next() {
+                ResultOption::Ok(item) => acc = f(acc, item),
+                ResultOption::None => return Ok(acc),
+                ResultOption::Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Collects all items into a collection, stopping at the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::try_iter::{self, TryIterator};
+    ///
+    /// let items = try_iter::convert([Ok(1), Ok(2)]);
+    /// assert_eq!(items.collect::<Vec<_>>(), Ok::<_, &str>(vec![1, 2]));
+    ///
+    /// let items = try_iter::convert([Ok(1), Err("broken"), Ok(2)]);
+    /// assert_eq!(items.collect::<Vec<_>>(), Err("broken"));
+    /// ```
+    fn collect<C>(mut self) -> Result<C, Self::Error>
+    where
+        Self: Sized,
+        C: FromIterator<Self::Item>,
+    {
+        let mut error = None;
+        let collection = core::iter::from_fn(|| match self.next() {
+            ResultOption::Ok(item) => Some(item),
+            ResultOption::None => None,
+            ResultOption::Err(e) => {
+                error = Some(e);
+                None
+            }
+        })
+        .collect();
+        match error {
+            Some(e) => Err(e),
+            None => Ok(collection),
+        }
+    }
+
+    /// Converts into a standard `Iterator` yielding `Result<Self::Item, Self::Error>`.
+    ///
+    /// The returned iterator ends when `self` returns `None`. It does not stop after an
+    /// error, so that callers can decide whether to continue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use result_option::try_iter::{self, TryIterator};
+    ///
+    /// let items = try_iter::convert([Ok(1), Err("broken"), Ok(2)]);
+    /// let results: Vec<_> = items.iterator().collect();
+    /// assert_eq!(results, [Ok(1), Err("broken"), Ok(2)]);
+    /// ```
+    #[inline]
+    fn iterator(self) -> Iter<Self>
+    where
+        Self: Sized,
+    {
+        Iter { iter: self }
+    }
+}
+
+impl<I: TryIterator + ?Sized> TryIterator for &mut I {
+    type Item = I::Item;
+    type Error = I::Error;
+
+    #[inline]
+    fn next(&mut self) -> ResultOption<I::Item, I::Error> {
+        (**self).next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
+
+/// Converts an iterator of `Result`s into a [`TryIterator`].
+///
+/// # Examples
+///
+/// ```
+/// use result_option::ResultOption;
+/// use result_option::try_iter::{self, TryIterator};
+///
+/// let mut items = try_iter::convert(["1", "x"].into_iter().map(str::parse::<u32>));
+/// assert_eq!(items.next(), ResultOption::Ok(1));
+/// assert!(items.next().is_err());
+/// assert!(items.next().is_none());
+/// ```
+#[inline]
+pub fn convert<I, T, E>(iter: I) -> Convert<I::IntoIter>
+where
+    I: IntoIterator<Item = Result<T, E>>,
+{
+    Convert {
+        iter: iter.into_iter(),
+    }
+}
+
+/// A [`TryIterator`] over an iterator of `Result`s, created by [`convert`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Convert<I> {
+    iter: I,
+}
+
+impl<I, T, E> TryIterator for Convert<I>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = T;
+    type Error = E;
+
+    #[inline]
+    fn next(&mut self) -> ResultOption<T, E> {
+        self.iter.next().transpose().into()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// A standard `Iterator` over a [`TryIterator`], created by [`TryIterator::iterator`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Iter<I> {
+    iter: I,
+}
+
+impl<I: TryIterator> Iterator for Iter<I> {
+    type Item = Result<I::Item, I::Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            ResultOption::Ok(item) => Some(Ok(item)),
+            ResultOption::None => None,
+            ResultOption::Err(e) => Some(Err(e)),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Adapter created by [`TryIterator::map`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Map<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<B, I, F> TryIterator for Map<I, F>
+where
+    I: TryIterator,
+    F: FnMut(I::Item) -> B,
+{
+    type Item = B;
+    type Error = I::Error;
+
+    #[inline]
+    fn next(&mut self) -> ResultOption<B, I::Error> {
+        self.iter.next().map(&mut self.f)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Adapter created by [`TryIterator::map_err`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapErr<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<B, I, F> TryIterator for MapErr<I, F>
+where
+    I: TryIterator,
+    F: FnMut(I::Error) -> B,
+{
+    type Item = I::Item;
+    type Error = B;
+
+    #[inline]
+    fn next(&mut self) -> ResultOption<I::Item, B> {
+        self.iter.next().map_err(&mut self.f)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Adapter created by [`TryIterator::filter`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Filter<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<I, P> TryIterator for Filter<I, P>
+where
+    I: TryIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+    type Error = I::Error;
+
+    fn next(&mut self) -> ResultOption<I::Item, I::Error> {
+        loop {
+            match self.iter.next() {
+                ResultOption::Ok(item) if !(self.predicate)(&item) => {}
+                other => return other,
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// Adapter created by [`TryIterator::take`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Take<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<I: TryIterator> TryIterator for Take<I> {
+    type Item = I::Item;
+    type Error = I::Error;
+
+    #[inline]
+    fn next(&mut self) -> ResultOption<I::Item, I::Error> {
+        if self.n == 0 {
+            return ResultOption::None;
+        }
+        let next = self.iter.next();
+        if next.is_ok() {
+            self.n -= 1;
+        }
+        next
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.n == 0 {
+            return (0, Some(0));
+        }
+        // Errors do not count against `n`, so only the inner upper bound holds.
+        let (lower, upper) = self.iter.size_hint();
+        (lower.min(self.n), upper)
+    }
+}
+
+/// Adapter created by [`TryIterator::chain`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Chain<A, B> {
+    first: Option<A>,
+    second: B,
+}
+
+impl<A, B> TryIterator for Chain<A, B>
+where
+    A: TryIterator,
+    B: TryIterator<Item = A::Item, Error = A::Error>,
+{
+    type Item = A::Item;
+    type Error = A::Error;
+
+    #[inline]
+    fn next(&mut self) -> ResultOption<A::Item, A::Error> {
+        if let Some(first) = &mut self.first {
+            match first.next() {
+                ResultOption::None => self.first = None,
+                other => return other,
+            }
+        }
+        self.second.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.second.size_hint();
+        match &self.first {
+            Some(first) => {
+                let (first_lower, first_upper) = first.size_hint();
+                (
+                    first_lower.saturating_add(lower),
+                    first_upper.zip(upper).and_then(|(a, b)| a.checked_add(b)),
+                )
+            }
+            None => (lower, upper),
+        }
+    }
+}
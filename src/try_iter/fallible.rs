@@ -0,0 +1,104 @@
+use super::TryIterator;
+use crate::ResultOption;
+use fallible_iterator::FallibleIterator;
+
+/// A [`TryIterator`] over a [`FallibleIterator`].
+///
+/// # Examples
+///
+/// ```
+/// use fallible_iterator::FallibleIterator;
+/// use result_option::ResultOption;
+/// use result_option::try_iter::{FromFallible, TryIterator};
+///
+/// let fallible = fallible_iterator::convert([Ok::<_, &str>(1), Err("broken")].into_iter());
+/// let mut items = FromFallible::new(fallible);
+/// assert_eq!(items.next(), ResultOption::Ok(1));
+/// assert_eq!(items.next(), ResultOption::Err("broken"));
+/// assert_eq!(items.next(), ResultOption::None);
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FromFallible<I> {
+    iter: I,
+}
+
+impl<I: FallibleIterator> FromFallible<I> {
+    /// Wraps a [`FallibleIterator`] so it can be used as a [`TryIterator`].
+    #[inline]
+    pub fn new(iter: I) -> Self {
+        Self { iter }
+    }
+
+    /// Returns the wrapped iterator.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: FallibleIterator> TryIterator for FromFallible<I> {
+    type Item = I::Item;
+    type Error = I::Error;
+
+    #[inline]
+    fn next(&mut self) -> ResultOption<I::Item, I::Error> {
+        self.iter.next().into()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// A [`FallibleIterator`] over a [`TryIterator`].
+///
+/// # Examples
+///
+/// ```
+/// use fallible_iterator::FallibleIterator;
+/// use result_option::try_iter::{self, IntoFallible};
+///
+/// let items = try_iter::convert([Ok::<_, &str>(1), Ok(2)]);
+/// let fallible = IntoFallible::new(items);
+/// assert_eq!(fallible.map(|n| Ok(n * 2)).collect::<Vec<_>>(), Ok(vec![2, 4]));
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntoFallible<I> {
+    iter: I,
+}
+
+impl<I: TryIterator> IntoFallible<I> {
+    /// Wraps a [`TryIterator`] so it can be used as a [`FallibleIterator`].
+    #[inline]
+    pub fn new(iter: I) -> Self {
+        Self { iter }
+    }
+
+    /// Returns the wrapped iterator.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: TryIterator> FallibleIterator for IntoFallible<I> {
+    type Item = I::Item;
+    type Error = I::Error;
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<I::Item>, I::Error> {
+        match self.iter.next() {
+            ResultOption::Ok(item) => Ok(Some(item)),
+            ResultOption::None => Ok(None),
+            ResultOption::Err(e) => Err(e),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}